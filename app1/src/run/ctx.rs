@@ -20,6 +20,7 @@ use tokio::task::LocalKey;
 
 static CTX_INFO: OnceLock<ArcSwap<CtxInfo>> = OnceLock::new();
 static REFRESH_COUNT: AtomicU32 = AtomicU32::new(0);
+static SUPPORTED_LOCALES: [&str; 3] = ["en-CA", "pt-BR", "es-ES"];
 
 #[derive(Debug, Clone)]
 pub struct CtxInfo {
@@ -141,7 +142,7 @@ impl Source<Parts> for SubCtx {
 
 impl Locale for SubCtx {
     fn locale() -> impl std::ops::Deref<Target = str> {
-        locale_from_task_local::<Self>("en-CA")
+        locale_from_task_local::<Self>(&SUPPORTED_LOCALES, "en-CA")
     }
 }
 
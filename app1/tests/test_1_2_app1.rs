@@ -38,7 +38,7 @@ impl<const K: u8> DbCtx for Ctx<K> {
 
 impl<const K: u8> Locale for SubCtx<K> {
     fn locale() -> impl std::ops::Deref<Target = str> + Send {
-        locale_from_task_local::<Self>("en-CA")
+        locale_from_task_local::<Self>(&["en-CA", "pt-BR", "es-ES"], "en-CA")
     }
 }
 
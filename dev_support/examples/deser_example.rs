@@ -5,24 +5,26 @@
 use foa::error::{self, BacktraceSpec, DeserError, FullKind, Tag};
 use serde::{Deserialize, Serialize};
 
-static FOO_TAG: Tag = Tag("FOO");
+static FOO_TAG: Tag = Tag::new("FOO");
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 struct Pld(String);
 
 /// Used to construct errors without sensitive data.
 static FOO_ERROR: FullKind<Pld, 1> =
-    FullKind::new_with_payload("FOO_ERROR", Some("foo message: {xyz}"), &FOO_TAG)
-        .with_prop_names(["xyz"])
+    FullKind::new_with_props("FOO_ERROR", Some("foo message: {xyz}"), &FOO_TAG, ["xyz"])
         .with_backtrace(BacktraceSpec::Env);
 
-static BAR_TAG: Tag = Tag("BAR");
+static BAR_TAG: Tag = Tag::new("BAR");
 
 /// Used to construct errors with sensitive data.
-static BAR_ERROR: FullKind<Pld, 2> =
-    FullKind::new_with_payload("BAR_ERROR", Some("bar message: {abc}, {!email}"), &BAR_TAG)
-        .with_prop_names(["abc", "!email"])
-        .with_backtrace(BacktraceSpec::Env);
+static BAR_ERROR: FullKind<Pld, 2> = FullKind::new_with_props(
+    "BAR_ERROR",
+    Some("bar message: {abc}, {!email}"),
+    &BAR_TAG,
+    ["abc", "!email"],
+)
+.with_backtrace(BacktraceSpec::Env);
 
 #[test]
 fn test() -> Result<(), Box<dyn std::error::Error>> {
@@ -32,8 +34,7 @@ fn test() -> Result<(), Box<dyn std::error::Error>> {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Non-sensitive info examples with `SerError` without payload or source.
     {
-        let err =
-            FOO_ERROR.error_with_values_payload(["hi there!".into()], Pld("foo-payload".into()));
+        let err = FOO_ERROR.error_with_values_payload(["hi there!"], Pld("foo-payload".into()));
         println!("*** err={err:?}");
         let ser_err =
             err.to_sererror_no_payload_src([error::StringSpec::Dbg, error::StringSpec::Recursive]);
@@ -52,8 +53,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Non-sensitive info examples with `SerError` with payload.
     {
-        let err0 =
-            FOO_ERROR.error_with_values_payload(["hi there!".into()], Pld("foo-payload".into()));
+        let err0 = FOO_ERROR.error_with_values_payload(["hi there!"], Pld("foo-payload".into()));
         let err = err0.downcast_payload::<Pld>()?;
         println!("*** err={err:?}");
 
@@ -89,10 +89,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Sensitive info examples with `SerError` and payload.
     {
-        let err0 = BAR_ERROR.error_with_values_payload(
-            ["hi there!".into(), "bar@example.com".into()],
-            Pld("bar-payload".into()),
-        );
+        let err0 = BAR_ERROR
+            .error_with_values_payload(["hi there!", "bar@example.com"], Pld("bar-payload".into()));
         let err = err0.downcast_payload::<Pld>()?;
         println!("*** err={err:?}");
 
@@ -4,11 +4,11 @@ use foa::{
     Error,
 };
 
-static FOO_TAG: Tag = Tag("FOO");
+static FOO_TAG: Tag = Tag::new("FOO");
 
-static FOO_ERROR: PropsKind<1> = PropsKind::new("FOO_ERROR", Some("foo message: {xyz}"), &FOO_TAG)
-    .with_prop_names(["xyz"])
-    .with_backtrace(BacktraceSpec::Yes);
+static FOO_ERROR: PropsKind<1> =
+    PropsKind::new_with_props("FOO_ERROR", Some("foo message: {xyz}"), &FOO_TAG, ["xyz"])
+        .with_backtrace(BacktraceSpec::Yes);
 
 static BAR_ERROR: BasicKind<Error> =
     BasicKind::new("BAR_ERROR", Some("bar message"), &FOO_TAG).with_backtrace(BacktraceSpec::Env);
@@ -1,25 +1,25 @@
 use foa::error::{self, BasicKind, Error, PropsKind, StdBoxError, TrivialError, UNEXPECTED_ERROR};
 use foa::error::{BacktraceSpec, Tag};
 
-static EG_TAG: Tag = Tag("EG");
+static EG_TAG: Tag = Tag::new("EG");
 
 static ERROR0: BasicKind = BasicKind::new("ERROR0", Some("error kind with no args"), &EG_TAG)
     .with_backtrace(BacktraceSpec::Env);
 
-static ERROR1: PropsKind<1, Error> = PropsKind::new(
+static ERROR1: PropsKind<1, Error> = PropsKind::new_with_props(
     "ERROR1",
     Some("error kind with '{xyz}' as single arg"),
     &EG_TAG,
+    ["xyz"],
 )
-.with_prop_names(["xyz"])
 .with_backtrace(BacktraceSpec::Env);
 
-static ERROR2: PropsKind<2, Error> = PropsKind::new(
+static ERROR2: PropsKind<2, Error> = PropsKind::new_with_props(
     "ERROR2",
     Some("error kind with '{aaa}' and '{bbb}' as args"),
     &EG_TAG,
+    ["aaa", "bbb"],
 )
-.with_prop_names(["aaa", "bbb"])
 .with_backtrace(BacktraceSpec::Env);
 
 fn error0() -> Error {
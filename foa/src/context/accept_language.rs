@@ -0,0 +1,135 @@
+//! Parsing of the HTTP `Accept-Language` header and locale negotiation as per
+//! [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#name-accept-language) and
+//! [RFC 4647](https://www.rfc-editor.org/rfc/rfc4647).
+
+use crate::string;
+
+/// A language range from an `Accept-Language` header, with its quality value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LanguageRange<'a> {
+    pub range: &'a str,
+    pub q: f32,
+}
+
+impl LanguageRange<'_> {
+    pub fn is_wildcard(&self) -> bool {
+        self.range == "*"
+    }
+
+    /// Whether `tag` is matched by this range as per RFC 4647 basic filtering.
+    fn matches(&self, tag: &str) -> bool {
+        self.is_wildcard()
+            || tag.eq_ignore_ascii_case(self.range)
+            || tag.len() > self.range.len()
+                && tag.as_bytes()[self.range.len()] == b'-'
+                && tag[..self.range.len()].eq_ignore_ascii_case(self.range)
+    }
+}
+
+fn is_valid_range(range: &str) -> bool {
+    range == "*"
+        || range.split('-').enumerate().all(|(i, subtag)| {
+            (1..=8).contains(&subtag.len())
+                && subtag.chars().all(|c| match i {
+                    0 => c.is_ascii_alphabetic(),
+                    _ => c.is_ascii_alphanumeric(),
+                })
+        })
+}
+
+fn parse_q(param: &str) -> Option<f32> {
+    let (name, value) = param.split_once('=')?;
+    if !name.trim().eq_ignore_ascii_case("q") {
+        return None;
+    }
+    let q = value.trim().parse::<f32>().ok()?;
+    (0.0..=1.0).contains(&q).then_some(q)
+}
+
+/// Parses an `Accept-Language` header value into its language ranges, sorted by descending quality value.
+/// Ranges with the same quality value keep their order in the header. Malformed entries are skipped.
+pub fn parse_accept_language(header: &str) -> Vec<LanguageRange<'_>> {
+    let mut ranges = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let range = parts.next()?.trim();
+            if !is_valid_range(range) {
+                return None;
+            }
+            let q = match parts.next() {
+                None => 1.0,
+                Some(param) => parse_q(param)?,
+            };
+            Some(LanguageRange { range, q })
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by(|r1, r2| r2.q.total_cmp(&r1.q));
+    ranges
+}
+
+/// Returns the element of `supported` that best matches the `Accept-Language` header value `header`, if any.
+///
+/// Language ranges are considered in order of preference. For each range, an exact (case-insensitive) match is
+/// preferred, followed by matches of the range's progressively less specific prefixes (RFC 4647 lookup), each either
+/// exact or as a prefix of a supported locale (RFC 4647 basic filtering). A wildcard range matches the first
+/// supported locale. Locales matched by a range with a quality value of 0 are never returned.
+pub fn negotiate_locale<'a>(header: &str, supported: &[&'a str]) -> Option<&'a str> {
+    let ranges = parse_accept_language(header);
+    let (accepted, excluded): (Vec<_>, Vec<_>) = ranges.into_iter().partition(|r| r.q > 0.0);
+    let acceptable = |tag: &&str| !excluded.iter().any(|r| r.matches(tag));
+
+    accepted.iter().find_map(|r| {
+        if r.is_wildcard() {
+            return supported.iter().copied().find(acceptable);
+        }
+        string::locale_fallbacks(r.range).find_map(|prefix| {
+            let prefix = LanguageRange {
+                range: prefix,
+                q: r.q,
+            };
+            let candidates = || supported.iter().copied().filter(acceptable);
+            candidates()
+                .find(|tag| tag.eq_ignore_ascii_case(prefix.range))
+                .or_else(|| candidates().find(|tag| prefix.matches(tag)))
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static SUPPORTED: [&str; 4] = ["en-CA", "en", "pt-BR", "fr"];
+
+    #[test]
+    fn test_parse_accept_language() {
+        let ranges = parse_accept_language("fr;q=0.5, en-CA, de;q=0.7, *;q=0.1, bad range, es;q=2");
+        let ranges = ranges.iter().map(|r| (r.range, r.q)).collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![("en-CA", 1.0), ("de", 0.7), ("fr", 0.5), ("*", 0.1)]
+        );
+    }
+
+    #[test]
+    fn test_negotiate_locale() {
+        let cases = [
+            ("pt-BR", Some("pt-BR")),
+            ("PT-br", Some("pt-BR")),
+            ("en-GB", Some("en")),
+            ("en-CA-x-foo", Some("en-CA")),
+            ("pt", Some("pt-BR")),
+            ("es, fr;q=0.8", Some("fr")),
+            ("de;q=0.9, en-CA;q=0.95", Some("en-CA")),
+            ("es", None),
+            ("es, *;q=0.5", Some("en-CA")),
+            ("en-CA;q=0, en;q=0, *", Some("pt-BR")),
+            ("fr;q=0", None),
+            ("", None),
+        ];
+        for (header, expected) in cases {
+            assert_eq!(negotiate_locale(header, &SUPPORTED), expected, "{header}");
+        }
+    }
+}
@@ -1,3 +1,9 @@
+mod accept_language;
+pub use accept_language::*;
+
+mod msg_catalog;
+pub use msg_catalog::*;
+
 use std::{fmt::Debug, ops::Deref};
 
 // region:      --- Context traits
@@ -18,6 +24,16 @@ pub trait Locale {
 
 pub trait LocaleSelf {
     fn locale(&self) -> Option<&str>;
+
+    /// Returns the element of `supported` that best matches `self`'s locale preferences, if any.
+    fn supported_locale<'a>(&self, supported: &[&'a str]) -> Option<&'a str> {
+        negotiate_locale(self.locale()?, supported)
+    }
+}
+
+pub trait RequestIdSelf {
+    /// Returns the id of the request or trace `self` belongs to, if any.
+    fn request_id(&self) -> Option<&str>;
 }
 
 pub trait LocaleCtx {
@@ -0,0 +1,302 @@
+use super::LocalizedMsg;
+use crate::{
+    error::{PropsKind, StdBoxError, INTERNAL_TAG, RUNTIME_TAG},
+    string, Result,
+};
+use std::{collections::HashMap, fs, iter, ops::Deref, path::Path};
+
+//===========================
+// region:      --- Error kinds
+
+pub static MSG_CATALOG_IO_ERROR: PropsKind<1, std::io::Error> = PropsKind::new_with_props(
+    "MSG_CATALOG_IO_ERROR",
+    Some("unable to read message catalog file {path}"),
+    &RUNTIME_TAG,
+    ["path"],
+);
+
+pub static MSG_CATALOG_PARSE_ERROR: PropsKind<1, StdBoxError> = PropsKind::new_with_props(
+    "MSG_CATALOG_PARSE_ERROR",
+    Some("unable to parse message catalog for locale {locale}"),
+    &INTERNAL_TAG,
+    ["locale"],
+);
+
+pub static MSG_CATALOG_MISMATCH_ERROR: PropsKind<2> = PropsKind::new_with_props(
+    "MSG_CATALOG_MISMATCH_ERROR",
+    Some("placeholders of message template for kind {kind_id} and locale {locale} do not match the kind's prop names"),
+    &INTERNAL_TAG,
+    ["kind_id", "locale"],
+);
+
+// endregion:   --- Error kinds
+
+//===========================
+// region:      --- CatalogFormat
+
+/// Supported message catalog file formats. Each catalog file contains a flat map from kind ids to
+/// message templates for a single locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogFormat {
+    Json,
+    Toml,
+}
+
+impl CatalogFormat {
+    /// Returns the format corresponding to a file extension, if any.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
+// endregion:   --- CatalogFormat
+
+//===========================
+// region:      --- MsgCatalog
+
+/// Table of localized message templates, keyed by locale and kind id.
+///
+/// Lookups fall back from the requested locale to progressively less specific locales and then to the
+/// default locale, e.g., `pt-BR` -> `pt` -> default.
+#[derive(Debug, Clone)]
+pub struct MsgCatalog {
+    default_locale: String,
+    templates: HashMap<String, HashMap<String, String>>,
+}
+
+impl MsgCatalog {
+    pub fn new(default_locale: impl Into<String>) -> Self {
+        Self {
+            default_locale: default_locale.into(),
+            templates: HashMap::new(),
+        }
+    }
+
+    /// Creates a catalog from the files in directory `dir` (see [`Self::load_dir`]) and validates it against
+    /// `kinds` (see [`Self::validate`]).
+    pub fn from_dir<'a>(
+        dir: impl AsRef<Path>,
+        default_locale: impl Into<String>,
+        kinds: impl IntoIterator<Item = (&'a str, &'a [&'a str])>,
+    ) -> Result<Self> {
+        let mut catalog = Self::new(default_locale);
+        catalog.load_dir(dir)?;
+        catalog.validate(kinds)?;
+        Ok(catalog)
+    }
+
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// Adds templates for `locale`, replacing any existing templates for the same kind ids.
+    pub fn add_templates(
+        &mut self,
+        locale: impl Into<String>,
+        templates: impl IntoIterator<Item = (String, String)>,
+    ) {
+        self.templates
+            .entry(locale.into())
+            .or_default()
+            .extend(templates);
+    }
+
+    /// Parses `content` in the given `format` and adds the resulting templates for `locale`.
+    pub fn add_str(&mut self, locale: &str, format: CatalogFormat, content: &str) -> Result<()> {
+        let templates: HashMap<String, String> = match format {
+            CatalogFormat::Json => serde_json::from_str(content).map_err(StdBoxError::new),
+            CatalogFormat::Toml => toml::from_str(content).map_err(StdBoxError::new),
+        }
+        .map_err(|err| MSG_CATALOG_PARSE_ERROR.error_with_values_src([locale], err))?;
+        self.add_templates(locale, templates);
+        Ok(())
+    }
+
+    /// Reads the file at `path` in the given `format` and adds the resulting templates for `locale`.
+    pub fn load_file(
+        &mut self,
+        locale: &str,
+        format: CatalogFormat,
+        path: impl AsRef<Path>,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|err| {
+            MSG_CATALOG_IO_ERROR.error_with_values_src([path.to_string_lossy()], err)
+        })?;
+        self.add_str(locale, format, &content)
+    }
+
+    /// Loads all files in directory `dir` named `<locale>.json` or `<locale>.toml`, e.g., `pt-BR.json`.
+    /// Other files are ignored.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<()> {
+        let dir = dir.as_ref();
+        let io_error =
+            |err| MSG_CATALOG_IO_ERROR.error_with_values_src([dir.to_string_lossy()], err);
+        for entry in fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            let locale = path.file_stem().and_then(|stem| stem.to_str());
+            let format = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(CatalogFormat::from_extension);
+            if let (Some(locale), Some(format)) = (locale, format) {
+                self.load_file(locale, format, &path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that, for each `(kind_id, prop_names)` pair in `kinds`, the placeholders of every template for
+    /// `kind_id` are exactly the kind's `prop_names`. Templates for kind ids not in `kinds` are not checked.
+    pub fn validate<'a>(
+        &self,
+        kinds: impl IntoIterator<Item = (&'a str, &'a [&'a str])>,
+    ) -> Result<()> {
+        for (kind_id, prop_names) in kinds {
+            for (locale, templates) in &self.templates {
+                let Some(template) = templates.get(kind_id) else {
+                    continue;
+                };
+                let placeholders = string::placeholder_names(template);
+                let matches = placeholders.iter().all(|name| prop_names.contains(name))
+                    && prop_names.iter().all(|name| placeholders.contains(name));
+                if !matches {
+                    return Err(MSG_CATALOG_MISMATCH_ERROR.error_with_values([kind_id, locale]));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the template for `kind_id`, looking up `locale` and its fallbacks.
+    pub fn template(&self, kind_id: &str, locale: &str) -> Option<&str> {
+        string::locale_fallbacks(locale)
+            .chain(iter::once(self.default_locale.as_str()))
+            .find_map(|locale| self.templates.get(locale)?.get(kind_id))
+            .map(|template| template.as_str())
+    }
+}
+
+// endregion:   --- MsgCatalog
+
+//===========================
+// region:      --- StaticMsgCatalog
+
+/// Provides access to a [`MsgCatalog`] stored in a static variable. Types that implement this trait
+/// implement [`LocalizedMsg`] by looking up the catalog.
+pub trait StaticMsgCatalog {
+    /// Returns the catalog, or `None` if it has not been loaded yet, in which case no localized
+    /// messages are available.
+    fn msg_catalog() -> Option<&'static MsgCatalog>;
+}
+
+impl<T: StaticMsgCatalog> LocalizedMsg for T {
+    fn localized_msg(kind: &str, locale: impl Deref<Target = str>) -> Option<&str> {
+        T::msg_catalog()?.template(kind, &locale)
+    }
+}
+
+// endregion:   --- StaticMsgCatalog
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BasicKind, Tag};
+    use std::{path::PathBuf, sync::OnceLock};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static FOO_ERROR: PropsKind<2> = PropsKind::new_with_props(
+        "FOO_ERROR",
+        Some("foo {abc} {!email}"),
+        &FOO_TAG,
+        ["abc", "!email"],
+    );
+
+    static BAR_ERROR: BasicKind = BasicKind::new("BAR_ERROR", Some("bar"), &FOO_TAG);
+
+    static CATALOG: OnceLock<MsgCatalog> = OnceLock::new();
+
+    struct CatalogCtx;
+
+    impl StaticMsgCatalog for CatalogCtx {
+        fn msg_catalog() -> Option<&'static MsgCatalog> {
+            CATALOG.get()
+        }
+    }
+
+    fn kinds() -> [(&'static str, &'static [&'static str]); 2] {
+        [
+            (FOO_ERROR.kind_id().0, FOO_ERROR.prop_names()),
+            (BAR_ERROR.kind_id().0, BAR_ERROR.prop_names()),
+        ]
+    }
+
+    fn make_catalog_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("foa-msg-catalog-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("en.json"),
+            r#"{"FOO_ERROR": "foo {abc} {!email}", "BAR_ERROR": "bar"}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("pt.toml"), r#"FOO_ERROR = "fu {!email} {abc}""#).unwrap();
+        fs::write(dir.join("pt-BR.json"), r#"{"BAR_ERROR": "barra"}"#).unwrap();
+        fs::write(dir.join("README.md"), "ignored").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_from_dir_and_fallback() {
+        let dir = make_catalog_dir();
+        let catalog = MsgCatalog::from_dir(&dir, "en", kinds()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(catalog.template("BAR_ERROR", "pt-BR"), Some("barra"));
+        assert_eq!(
+            catalog.template("FOO_ERROR", "pt-BR"),
+            Some("fu {!email} {abc}")
+        );
+        assert_eq!(catalog.template("BAR_ERROR", "pt"), Some("bar"));
+        assert_eq!(catalog.template("BAR_ERROR", "es-ES"), Some("bar"));
+        assert_eq!(catalog.template("BAR_ERROR", ""), Some("bar"));
+        assert_eq!(catalog.template("BAZ_ERROR", "pt-BR"), None);
+
+        CATALOG.get_or_init(|| catalog);
+        assert_eq!(
+            CatalogCtx::localized_msg("FOO_ERROR", "pt-PT"),
+            Some("fu {!email} {abc}")
+        );
+    }
+
+    #[test]
+    fn test_validate_mismatch() {
+        let mut catalog = MsgCatalog::new("en");
+        catalog
+            .add_str("en", CatalogFormat::Json, r#"{"FOO_ERROR": "foo {abc}"}"#)
+            .unwrap();
+        let err = catalog.validate(kinds()).unwrap_err();
+        assert!(err.has_kind(MSG_CATALOG_MISMATCH_ERROR.kind_id()));
+        assert_eq!(err.props().prop_str("kind_id"), Some("FOO_ERROR"));
+
+        let mut catalog = MsgCatalog::new("en");
+        catalog
+            .add_str("fr", CatalogFormat::Toml, r#"BAR_ERROR = "bar {xyz}""#)
+            .unwrap();
+        let err = catalog.validate(kinds()).unwrap_err();
+        assert_eq!(err.props().prop_str("locale"), Some("fr"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let mut catalog = MsgCatalog::new("en");
+        let err = catalog
+            .add_str("en", CatalogFormat::Json, "not json")
+            .unwrap_err();
+        assert!(err.has_kind(MSG_CATALOG_PARSE_ERROR.kind_id()));
+    }
+}
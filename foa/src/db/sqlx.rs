@@ -25,6 +25,7 @@ pub static DB_ERROR: BasicKind<sqlx::Error> =
         .with_backtrace(BacktraceSpec::Env);
 
 impl From<sqlx::Error> for Error {
+    #[track_caller]
     fn from(cause: sqlx::Error) -> Self {
         DB_ERROR.error_with_src(cause)
     }
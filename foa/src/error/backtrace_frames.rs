@@ -0,0 +1,291 @@
+use serde::Serialize;
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    fmt::Display,
+};
+
+//===========================
+// region:      --- BacktraceFrame
+
+/// A frame of a [`Backtrace`], as obtained from the backtrace's `Display` output (see [`backtrace_frames`]).
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct BacktraceFrame {
+    pub function: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// Number of subsequent frames collapsed into this one (see [`FrameFilter::collapse`]).
+    #[serde(skip_serializing_if = "is_zero")]
+    pub collapsed: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+/// Formats as, e.g., `foo::bar at ./src/foo.rs:12` or `tokio::runtime::park (+5 collapsed)`.
+impl Display for BacktraceFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.function)?;
+        if let Some(file) = &self.file {
+            write!(f, " at {file}")?;
+            if let Some(line) = self.line {
+                write!(f, ":{line}")?;
+            }
+        }
+        if self.collapsed > 0 {
+            write!(f, " (+{} collapsed)", self.collapsed)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the frames of `backtrace`, empty if no backtrace was captured.
+///
+/// This is best-effort: the frames are parsed from the backtrace's `Display` output, whose format is not
+/// specified by the standard library and may change. If no frames can be recognized in it, a single frame
+/// is returned whose `function` is the raw output, so that the backtrace is still shown.
+pub fn backtrace_frames(backtrace: &Backtrace) -> Vec<BacktraceFrame> {
+    if backtrace.status() != BacktraceStatus::Captured {
+        return Vec::new();
+    }
+    parse_frames(&backtrace.to_string())
+}
+
+/// Parses the `Display` output of a captured backtrace, falling back to the raw text as per
+/// [`backtrace_frames`].
+fn parse_frames(txt: &str) -> Vec<BacktraceFrame> {
+    let mut frames: Vec<BacktraceFrame> = Vec::new();
+    for line in txt.lines().map(str::trim) {
+        if let Some(location) = line.strip_prefix("at ") {
+            if let Some(frame) = frames.last_mut() {
+                let mut parts = location.rsplitn(3, ':');
+                let (col, line_no, file) = (parts.next(), parts.next(), parts.next());
+                match (file, line_no.and_then(|l| l.parse().ok()), col) {
+                    (Some(file), Some(line_no), Some(_)) => {
+                        frame.file = Some(file.to_owned());
+                        frame.line = Some(line_no);
+                    }
+                    _ => frame.file = Some(location.to_owned()),
+                }
+            }
+        } else if let Some((idx, function)) = line.split_once(": ") {
+            if !idx.is_empty() && idx.chars().all(|c| c.is_ascii_digit()) {
+                frames.push(BacktraceFrame {
+                    function: function.to_owned(),
+                    file: None,
+                    line: None,
+                    collapsed: 0,
+                });
+            }
+        }
+    }
+    if frames.is_empty() && !txt.trim().is_empty() {
+        frames.push(BacktraceFrame {
+            function: txt.trim_end().to_owned(),
+            file: None,
+            line: None,
+            collapsed: 0,
+        });
+    }
+    frames
+}
+
+// endregion:   --- BacktraceFrame
+
+//===========================
+// region:      --- FrameFilter
+
+/// Rules to filter the frames of a backtrace by crate prefix. A frame matches a prefix if its function
+/// name, ignoring leading `'<'` and `'&'` characters, starts with the prefix.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameFilter<'a> {
+    /// Frames matching any of these prefixes are dropped.
+    pub drop: &'a [&'a str],
+    /// Runs of consecutive frames matching any of these prefixes are collapsed into the first frame
+    /// of the run.
+    pub collapse: &'a [&'a str],
+}
+
+/// Drops standard library and unwinding frames and collapses async runtime and web framework frames.
+pub static DEFAULT_FRAME_FILTER: FrameFilter<'static> = FrameFilter {
+    drop: &[
+        "std::",
+        "core::",
+        "alloc::",
+        "backtrace::",
+        "__rustc::",
+        "rust_begin_unwind",
+        "test::",
+    ],
+    collapse: &[
+        "tokio::",
+        "axum::",
+        "axum_core::",
+        "hyper::",
+        "hyper_util::",
+        "tower::",
+        "futures_util::",
+        "futures_core::",
+    ],
+};
+
+fn matches(function: &str, prefixes: &[&str]) -> bool {
+    let function = function.trim_start_matches(['<', '&']);
+    prefixes.iter().any(|prefix| function.starts_with(prefix))
+}
+
+impl FrameFilter<'_> {
+    /// Returns the frames that remain after applying `self` to `frames`.
+    pub fn apply(&self, frames: Vec<BacktraceFrame>) -> Vec<BacktraceFrame> {
+        let mut res: Vec<BacktraceFrame> = Vec::new();
+        let mut in_run = false;
+        for frame in frames {
+            if matches(&frame.function, self.drop) {
+                continue;
+            }
+            let collapsible = matches(&frame.function, self.collapse);
+            match res.last_mut() {
+                Some(last) if collapsible && in_run => last.collapsed += 1 + frame.collapsed,
+                _ => res.push(frame),
+            }
+            in_run = collapsible;
+        }
+        res
+    }
+
+    /// Returns the filtered frames of `backtrace`.
+    pub fn frames(&self, backtrace: &Backtrace) -> Vec<BacktraceFrame> {
+        self.apply(backtrace_frames(backtrace))
+    }
+
+    /// Returns the filtered frames of `backtrace`, one numbered frame per line.
+    pub fn render(&self, backtrace: &Backtrace) -> String {
+        self.frames(backtrace)
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| format!("{i:>4}: {frame}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the filtered frames of `backtrace` as a JSON array of [`BacktraceFrame`]s.
+    pub fn render_json(&self, backtrace: &Backtrace) -> String {
+        serde_json::to_string(&self.frames(backtrace))
+            .expect("serialization of backtrace frames can't fail")
+    }
+}
+
+// endregion:   --- FrameFilter
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BacktraceSpec, BasicKind, StringSpec, Tag};
+
+    fn frame(function: &str) -> BacktraceFrame {
+        BacktraceFrame {
+            function: function.to_owned(),
+            file: None,
+            line: None,
+            collapsed: 0,
+        }
+    }
+
+    #[test]
+    fn test_filter() {
+        let frames = [
+            "foa::error::foo",
+            "<alloc::boxed::Box<F> as core::ops::function::FnOnce>::call_once",
+            "app::handler",
+            "tokio::runtime::task::poll",
+            "<tokio::runtime::blocking::Task as core::future::Future>::poll",
+            "std::panicking::try",
+            "tokio::runtime::park",
+            "app::main",
+        ]
+        .map(frame);
+        let mut run = frame("tokio::runtime::task::poll");
+        run.collapsed = 2;
+        assert_eq!(
+            DEFAULT_FRAME_FILTER.apply(frames.to_vec()),
+            [
+                frame("foa::error::foo"),
+                frame("app::handler"),
+                run,
+                frame("app::main")
+            ]
+        );
+    }
+
+    #[inline(never)]
+    fn capture() -> Backtrace {
+        Backtrace::force_capture()
+    }
+
+    #[test]
+    fn test_backtrace_frames() {
+        assert!(backtrace_frames(&Backtrace::disabled()).is_empty());
+
+        let bt = capture();
+        let frames = DEFAULT_FRAME_FILTER.frames(&bt);
+        let own = frames
+            .iter()
+            .find(|f| f.function.ends_with("backtrace_frames::test::capture"))
+            .unwrap();
+        assert!(own
+            .file
+            .as_deref()
+            .unwrap()
+            .ends_with("backtrace_frames.rs"));
+        assert!(own.line.is_some());
+        assert!(frames.iter().all(|f| !f.function.starts_with("std::")));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&DEFAULT_FRAME_FILTER.render_json(&bt)).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), frames.len());
+        assert!(DEFAULT_FRAME_FILTER
+            .render(&bt)
+            .contains("backtrace_frames::test::capture at "));
+    }
+
+    #[test]
+    fn test_parse_frames_fallback() {
+        let txt = "   0: foo::bar\n             at ./src/foo.rs:12:5\n   1: baz\n";
+        let mut bar = frame("foo::bar");
+        bar.file = Some("./src/foo.rs".to_owned());
+        bar.line = Some(12);
+        assert_eq!(parse_frames(txt), [bar, frame("baz")]);
+
+        let txt = "#0 0x55d0 in foo::bar src/foo.rs:12\n#1 0x55d1 in baz\n";
+        assert_eq!(
+            parse_frames(txt),
+            [frame(
+                "#0 0x55d0 in foo::bar src/foo.rs:12\n#1 0x55d1 in baz"
+            )]
+        );
+        assert!(parse_frames("").is_empty());
+    }
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static FOO_ERROR: BasicKind =
+        BasicKind::new("FRAMES_FOO_ERROR", None, &FOO_TAG).with_backtrace(BacktraceSpec::Yes);
+
+    #[test]
+    fn test_ser_error_frames() {
+        let err = FOO_ERROR.error();
+        let ser_err = err.to_sererror_no_payload_src([
+            StringSpec::FilteredBacktrace(&DEFAULT_FRAME_FILTER),
+            StringSpec::BacktraceJson(&DEFAULT_FRAME_FILTER),
+        ]);
+        let frames: Vec<serde_json::Value> =
+            serde_json::from_str(&ser_err.other()["backtrace_json"]).unwrap();
+        assert!(frames.iter().any(|f| f["function"]
+            .as_str()
+            .unwrap()
+            .ends_with("test_ser_error_frames")));
+        assert!(!ser_err.other()["filtered_backtrace_string"].contains("std::"));
+    }
+}
@@ -0,0 +1,266 @@
+use super::{BacktraceSpec, KindId, PropsKind, Result, Tag, INTERNAL_TAG};
+use arc_swap::ArcSwap;
+use std::{
+    backtrace::Backtrace,
+    collections::HashMap,
+    env,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+//===========================
+// region:      --- Error kinds
+
+pub static BACKTRACE_CONFIG_ERROR: PropsKind<1> = PropsKind::new_with_props(
+    "BACKTRACE_CONFIG_ERROR",
+    Some("invalid backtrace policy configuration: {reason}"),
+    &INTERNAL_TAG,
+    ["reason"],
+);
+
+// endregion:   --- Error kinds
+
+//===========================
+// region:      --- BacktraceMode
+
+/// Runtime backtrace generation mode for the errors of a kind or tag (see [`BacktracePolicy`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacktraceMode {
+    /// Same as the corresponding [`BacktraceSpec`].
+    Spec(BacktraceSpec),
+    /// A backtrace is generated for the first error and every Nth error thereafter.
+    EveryNth(u64),
+    /// A backtrace is generated for at most the given number of errors per second.
+    PerSecond(u64),
+}
+
+impl BacktraceMode {
+    /// Parses `yes`, `no`, `env`, `every:N`, or `per_sec:N`.
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        let mode = match value.split_once(':') {
+            None => match value.as_str() {
+                "yes" => Self::Spec(BacktraceSpec::Yes),
+                "no" => Self::Spec(BacktraceSpec::No),
+                "env" => Self::Spec(BacktraceSpec::Env),
+                _ => return None,
+            },
+            Some(("every", n)) => Self::EveryNth(n.trim().parse().ok().filter(|n| *n > 0)?),
+            Some(("per_sec", n)) => Self::PerSecond(n.trim().parse().ok()?),
+            Some(_) => return None,
+        };
+        Some(mode)
+    }
+}
+
+/// A [`BacktraceMode`] with the state needed for sampling.
+#[derive(Debug)]
+struct Rule {
+    mode: BacktraceMode,
+    count: AtomicU64,
+    /// Current second and number of backtraces generated in it, for [`BacktraceMode::PerSecond`].
+    window: Mutex<(u64, u64)>,
+}
+
+impl Rule {
+    fn new(mode: BacktraceMode) -> Self {
+        Self {
+            mode,
+            count: AtomicU64::new(0),
+            window: Mutex::new((0, 0)),
+        }
+    }
+
+    fn backtrace(&self) -> Backtrace {
+        let sampled = match self.mode {
+            BacktraceMode::Spec(spec) => return spec_backtrace(spec),
+            BacktraceMode::EveryNth(n) => {
+                // Not `u64::is_multiple_of`, which requires Rust 1.87.
+                self.count.fetch_add(1, Ordering::Relaxed).checked_rem(n) == Some(0)
+            }
+            BacktraceMode::PerSecond(max) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+                if window.0 != now {
+                    *window = (now, 0);
+                }
+                let sampled = window.1 < max;
+                if sampled {
+                    window.1 += 1;
+                }
+                sampled
+            }
+        };
+        if sampled {
+            Backtrace::force_capture()
+        } else {
+            Backtrace::disabled()
+        }
+    }
+}
+
+fn spec_backtrace(spec: BacktraceSpec) -> Backtrace {
+    match spec {
+        BacktraceSpec::Yes => Backtrace::force_capture(),
+        BacktraceSpec::No => Backtrace::disabled(),
+        BacktraceSpec::Env => Backtrace::capture(),
+    }
+}
+
+// endregion:   --- BacktraceMode
+
+//===========================
+// region:      --- BacktracePolicy
+
+/// Name of the environment variable that holds the backtrace policy configuration
+/// (see [`BacktracePolicy::from_env`]).
+pub const BACKTRACE_POLICY_VAR: &str = "FOA_BACKTRACE_POLICY";
+
+/// Runtime overrides of the compile-time [`BacktraceSpec`]s of error kinds, by kind id or by tag.
+/// A kind id override takes precedence over a tag override, and the override for a tag also applies to its
+/// descendants (see [`Tag::is_a`]) that have no override of their own. Errors whose kind and tag have no
+/// override use their kind's [`BacktraceSpec`].
+#[derive(Debug, Default)]
+pub struct BacktracePolicy {
+    by_kind: HashMap<String, Rule>,
+    by_tag: HashMap<String, Rule>,
+}
+
+impl BacktracePolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `self` with an override for the kind with id `kind_id`.
+    pub fn with_kind(mut self, kind_id: &KindId, mode: BacktraceMode) -> Self {
+        self.by_kind.insert(kind_id.0.to_owned(), Rule::new(mode));
+        self
+    }
+
+    /// Returns `self` with an override for the kinds with tag `tag`.
+    pub fn with_tag(mut self, tag: &Tag, mode: BacktraceMode) -> Self {
+        self.by_tag.insert(tag.name().to_owned(), Rule::new(mode));
+        self
+    }
+
+    /// Creates a policy from a configuration value consisting of comma-separated `target=mode` entries,
+    /// where `target` is a kind id or `tag:` followed by a tag name, and `mode` is one of `yes`, `no`, `env`,
+    /// `every:N`, or `per_sec:N` (see [`BacktraceMode`]).
+    /// For example, `UNEXPECTED_ERROR=per_sec:5,tag:INTERNAL=every:100`.
+    pub fn from_config(config: &str) -> Result<Self> {
+        let mut policy = Self::new();
+        for entry in config.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((target, mode)) = entry.split_once('=') else {
+                return Err(BACKTRACE_CONFIG_ERROR.error_with_values([entry]));
+            };
+            let Some(mode) = BacktraceMode::parse(mode) else {
+                return Err(BACKTRACE_CONFIG_ERROR.error_with_values([entry]));
+            };
+            match target.trim().strip_prefix("tag:") {
+                Some(tag) => policy.by_tag.insert(tag.trim().to_owned(), Rule::new(mode)),
+                None => policy
+                    .by_kind
+                    .insert(target.trim().to_owned(), Rule::new(mode)),
+            };
+        }
+        Ok(policy)
+    }
+
+    /// Creates a policy from the environment variable [`BACKTRACE_POLICY_VAR`] (see [`Self::from_config`]).
+    /// Returns an empty policy if the variable is not set.
+    pub fn from_env() -> Result<Self> {
+        match env::var(BACKTRACE_POLICY_VAR) {
+            Err(_) => Ok(Self::default()),
+            Ok(config) => Self::from_config(&config),
+        }
+    }
+
+    /// Returns the backtrace for a new error of the given kind id and tag, whose kind has backtrace
+    /// spec `spec`.
+    pub fn backtrace(&self, kind_id: &KindId, tag: &Tag, spec: BacktraceSpec) -> Backtrace {
+        match self
+            .by_kind
+            .get(kind_id.0)
+            .or_else(|| tag.ancestors().find_map(|tag| self.by_tag.get(tag.name())))
+        {
+            Some(rule) => rule.backtrace(),
+            None => spec_backtrace(spec),
+        }
+    }
+}
+
+fn policy_static() -> &'static ArcSwap<BacktracePolicy> {
+    static POLICY: OnceLock<ArcSwap<BacktracePolicy>> = OnceLock::new();
+    POLICY.get_or_init(|| ArcSwap::from_pointee(BacktracePolicy::default()))
+}
+
+/// Returns the current backtrace policy, empty unless set with [`set_backtrace_policy`].
+pub fn backtrace_policy() -> Arc<BacktracePolicy> {
+    policy_static().load_full()
+}
+
+/// Sets the backtrace policy used from now on. Typically called at application startup, e.g., with
+/// [`BacktracePolicy::from_env`]. Sampling state starts afresh with the new policy.
+pub fn set_backtrace_policy(policy: BacktracePolicy) {
+    policy_static().store(Arc::new(policy));
+}
+
+/// Returns the backtrace for a new error according to the current backtrace policy.
+pub(crate) fn policy_backtrace(kind_id: &KindId, tag: &Tag, spec: BacktraceSpec) -> Backtrace {
+    policy_static().load().backtrace(kind_id, tag, spec)
+}
+
+// endregion:   --- BacktracePolicy
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::backtrace::BacktraceStatus;
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static BAR_TAG: Tag = Tag::new("BAR");
+
+    fn captured(bt: Backtrace) -> bool {
+        bt.status() == BacktraceStatus::Captured
+    }
+
+    #[test]
+    fn test_backtrace_policy() {
+        let foo = KindId("FOO_ERROR");
+        let bar = KindId("BAR_ERROR");
+        let policy = BacktracePolicy::from_config(" FOO_ERROR = every:3, tag:BAR=no ").unwrap();
+
+        let foo_captures = (0..7)
+            .map(|_| captured(policy.backtrace(&foo, &BAR_TAG, BacktraceSpec::Yes)))
+            .collect::<Vec<_>>();
+        assert_eq!(foo_captures, [true, false, false, true, false, false, true]);
+        assert!(!captured(policy.backtrace(
+            &bar,
+            &BAR_TAG,
+            BacktraceSpec::Yes
+        )));
+        assert!(captured(policy.backtrace(
+            &bar,
+            &FOO_TAG,
+            BacktraceSpec::Yes
+        )));
+
+        let policy = BacktracePolicy::new().with_kind(&foo, BacktraceMode::PerSecond(2));
+        let captures = (0..5)
+            .filter(|_| captured(policy.backtrace(&foo, &FOO_TAG, BacktraceSpec::No)))
+            .count();
+        assert!((2..=4).contains(&captures));
+
+        for config in ["FOO_ERROR", "FOO_ERROR=every:0", "tag:FOO=sometimes"] {
+            let err = BacktracePolicy::from_config(config).unwrap_err();
+            assert!(err.has_kind(BACKTRACE_CONFIG_ERROR.kind_id()));
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::error::utils;
 use serde::Serialize;
 use serde_json::Value;
 use std::{
-    any::Any,
+    any::{type_name, Any},
     error::Error as StdError,
     fmt::{Debug, Display},
     mem::replace,
@@ -34,11 +34,27 @@ impl StdError for Box<dyn JserError> {
 
 // region:      --- StdBoxError
 
-pub struct StdBoxError(pub(crate) Box<dyn StdError + Send + Sync + 'static>);
+/// Boxed error, which keeps the type name of the boxed error.
+///
+/// The type name is kept as a function pointer, which is half the size of a `&str`, to keep
+/// [`Error`](super::Error) and therefore [`Result`](super::Result) small.
+pub struct StdBoxError(
+    pub(crate) Box<dyn StdError + Send + Sync + 'static>,
+    pub(crate) fn() -> &'static str,
+);
 
 impl StdBoxError {
-    pub fn new(inner: impl StdError + Send + Sync + 'static) -> Self {
-        Self(Box::new(inner))
+    pub fn new<T: StdError + Send + Sync + 'static>(inner: T) -> Self {
+        Self(Box::new(inner), type_name::<T>)
+    }
+
+    pub(crate) fn from_box<T: StdError + Send + Sync + 'static>(inner: Box<T>) -> Self {
+        Self(inner, type_name::<T>)
+    }
+
+    /// Type name of the boxed error.
+    pub fn type_name(&self) -> &'static str {
+        (self.1)()
     }
 
     pub fn as_dyn_std_error(&self) -> &(dyn StdError + 'static) {
@@ -1,8 +1,9 @@
 use super::{
-    BoxPayload, Fmt, KindId, KindTypeInfo, NullError, Payload, Props, SendSyncStaticError,
-    SerError, StaticStr, StdBoxError, StringSpec, Tag, WithBacktrace,
+    rfc3339_utc, ser_source_chain, BoxPayload, Fmt, KindId, KindTypeInfo, NullError, Payload,
+    Props, SendSyncStaticError, SerError, SerSource, StaticStr, StdBoxError, StringSpec, Tag,
+    Trail, WithBacktrace,
 };
-use crate::nodebug::NoDebug;
+use crate::{context::ErrCtx, nodebug::NoDebug, string};
 use serde::Serialize;
 use std::{
     any::{type_name, TypeId},
@@ -10,6 +11,8 @@ use std::{
     collections::BTreeMap,
     error::Error as StdError,
     fmt::{Debug, Display},
+    panic::Location,
+    time::SystemTime,
 };
 
 pub const TRUNC: usize = 8;
@@ -27,7 +30,6 @@ pub type ReverseResult<T, PLD = BoxPayload, SRC = StdBoxError> =
 //===========================
 // region:      --- Error type and constructors
 
-#[derive(Debug)]
 pub struct Error<PLD = BoxPayload, SRC = StdBoxError> {
     pub(crate) kind_id: &'static KindId,
     pub(super) msg: StaticStr,
@@ -35,11 +37,41 @@ pub struct Error<PLD = BoxPayload, SRC = StdBoxError> {
     pub(crate) props: Props,
     pub(crate) payload: PLD,
     pub(crate) src: Option<SRC>,
+    pub(crate) meta: Box<ErrorMeta>,
+}
+
+/// Backtrace, ref id, [`Trail`], location, and creation time of an [`Error`]. Boxed in [`Error`], as these
+/// are less often used than its other fields, to keep [`Error`] and therefore [`Result`] small.
+#[derive(Debug)]
+pub struct ErrorMeta {
     pub(crate) backtrace: NoDebug<Backtrace>,
     pub(crate) ref_id: Option<String>,
+    pub(crate) trail: Trail,
+    pub(crate) location: &'static Location<'static>,
+    pub(crate) created_at: SystemTime,
+    /// Type name of the source of a statically typed error, recorded when the error is built, as its source
+    /// is not a [`StdBoxError`], which keeps its own type name.
+    pub(crate) src_type_name: Option<&'static str>,
+}
+
+impl ErrorMeta {
+    /// Creates metadata with an empty [`Trail`], whose location is the location of the caller and whose
+    /// creation time is the current time.
+    #[track_caller]
+    pub fn new(backtrace: Backtrace, ref_id: Option<String>) -> Self {
+        Self {
+            backtrace: NoDebug(backtrace),
+            ref_id,
+            trail: Trail::default(),
+            location: Location::caller(),
+            created_at: SystemTime::now(),
+            src_type_name: None,
+        }
+    }
 }
 
 impl Error {
+    /// Creates an error whose [`Self::location`] and [`Self::created_at`] are those of `meta`.
     pub fn new(
         kind_id: &'static KindId,
         msg: &'static str,
@@ -47,8 +79,7 @@ impl Error {
         props: Props,
         payload: impl Payload,
         source: Option<StdBoxError>,
-        backtrace: Backtrace,
-        ref_id: Option<String>,
+        meta: ErrorMeta,
     ) -> Self {
         Self {
             kind_id,
@@ -57,11 +88,11 @@ impl Error {
             props,
             payload: BoxPayload::new(payload),
             src: source,
-            backtrace: NoDebug(backtrace),
-            ref_id,
+            meta: Box::new(meta),
         }
     }
 
+    /// Same as [`Self::new`] but with an owned message.
     pub fn with_msg_string(
         kind_id: &'static KindId,
         msg: String,
@@ -69,8 +100,7 @@ impl Error {
         props: Props,
         payload: impl Payload,
         source: Option<StdBoxError>,
-        backtrace: Backtrace,
-        ref_id: Option<String>,
+        meta: ErrorMeta,
     ) -> Self {
         Self {
             kind_id,
@@ -79,8 +109,7 @@ impl Error {
             props,
             payload: BoxPayload::new(payload),
             src: source,
-            backtrace: NoDebug(backtrace),
-            ref_id,
+            meta: Box::new(meta),
         }
     }
 }
@@ -103,6 +132,20 @@ impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
         &self.msg
     }
 
+    /// Returns the message with its `{name}` placeholders replaced by the values of the corresponding
+    /// [`Props`]. Protected values are rendered as per [`Props::safe_interpolated`].
+    pub fn interpolated_msg(&self) -> String {
+        self.props.safe_interpolated(&self.msg)
+    }
+
+    /// Returns the message template localized by `CTX` for [`Self::kind_id`], interpolated as in
+    /// [`Self::interpolated_msg`]. Falls back to the kind's default message if `CTX` has no localized
+    /// template for the kind.
+    pub fn localized_msg<CTX: ErrCtx>(&self) -> String {
+        let raw_msg = string::localized::<CTX>(self.kind_id.0).unwrap_or(self.msg());
+        self.props.safe_interpolated(raw_msg)
+    }
+
     pub fn tag(&self) -> &'static Tag {
         self.tag
     }
@@ -120,37 +163,80 @@ impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
     }
 
     pub fn backtrace(&self) -> &Backtrace {
-        &self.backtrace
+        &self.meta.backtrace
     }
 
     pub fn ref_id(&self) -> Option<&str> {
-        self.ref_id.as_deref()
+        self.meta.ref_id.as_deref()
+    }
+
+    /// Source location where `self` was created.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.meta.location
+    }
+
+    /// Time when `self` was created.
+    pub fn created_at(&self) -> SystemTime {
+        self.meta.created_at
     }
 
     pub fn as_fmt(&self) -> Fmt<'_, Self> {
         Fmt(self)
     }
 
-    pub fn to_sererror_no_payload_src<const N: usize>(
-        &self,
-        str_specs: [StringSpec; N],
-    ) -> SerError<Box<()>, Box<NullError>> {
+    /// Returns the [`SerSource`]s of the errors in the source chain of `self` (see [`ser_source_chain`]).
+    pub fn ser_source_chain(&self) -> Vec<SerSource> {
+        let mut chain = ser_source_chain(self);
+        if let (Some(first), Some(type_name)) = (chain.first_mut(), self.meta.src_type_name) {
+            first.type_name = type_name;
+        }
+        chain
+    }
+
+    /// Returns the [`SerError`] of `self` without payload and source. `str_specs` determine the
+    /// [`SerError::other`] strings, except for [`StringSpec::Location`], [`StringSpec::CreatedAt`], and
+    /// [`StringSpec::SourceChain`], which determine whether [`SerError::location`], [`SerError::created_at`],
+    /// and [`SerError::source_chain`] are included.
+    fn ser_error<P, S>(&self, str_specs: &[StringSpec]) -> SerError<P, S> {
         let fmt = Fmt(self);
-        let other = str_specs
-            .into_iter()
-            .map(|spec| fmt.speced_string_tuple(&spec))
-            .collect::<BTreeMap<&'static str, String>>();
+        let mut other = BTreeMap::new();
+        let mut location = None;
+        let mut created_at = None;
+        let mut source_chain = Vec::new();
+        for spec in str_specs {
+            match spec {
+                StringSpec::Location => location = Some(self.meta.location.to_string()),
+                StringSpec::CreatedAt => created_at = Some(rfc3339_utc(self.meta.created_at)),
+                StringSpec::SourceChain => source_chain = self.ser_source_chain(),
+                _ => {
+                    let (name, value) = fmt.speced_string_tuple(spec);
+                    other.insert(name, value);
+                }
+            }
+        }
         SerError {
             kind_id: self.kind_id,
-            msg: self.msg.clone(),
+            msg: self.interpolated_msg().into(),
             tag: self.tag,
             props: self.props.clone(),
             payload: None,
             src: None,
+            ref_id: self.meta.ref_id.clone(),
+            trail: self.meta.trail.clone(),
+            location,
+            created_at,
+            source_chain,
             other,
         }
     }
 
+    pub fn to_sererror_no_payload_src<const N: usize>(
+        &self,
+        str_specs: [StringSpec; N],
+    ) -> SerError<Box<()>, Box<NullError>> {
+        self.ser_error(&str_specs)
+    }
+
     pub fn into_sererror_with_payload<const N: usize>(
         self,
         str_specs: [StringSpec; N],
@@ -158,19 +244,10 @@ impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
     where
         PLD: Serialize,
     {
-        let fmt = Fmt(&self);
-        let other = str_specs
-            .into_iter()
-            .map(|spec| fmt.speced_string_tuple(&spec))
-            .collect::<BTreeMap<&'static str, String>>();
+        let ser_err = self.ser_error(&str_specs);
         SerError {
-            kind_id: self.kind_id,
-            msg: self.msg.clone(),
-            tag: self.tag,
-            props: self.props.clone(),
             payload: Some(self.payload),
-            src: None,
-            other,
+            ..ser_err
         }
     }
 
@@ -181,19 +258,10 @@ impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
     where
         SRC: Serialize,
     {
-        let fmt = Fmt(&self);
-        let other = str_specs
-            .into_iter()
-            .map(|spec| fmt.speced_string_tuple(&spec))
-            .collect::<BTreeMap<&'static str, String>>();
+        let ser_err = self.ser_error(&str_specs);
         SerError {
-            kind_id: self.kind_id,
-            msg: self.msg.clone(),
-            tag: self.tag,
-            props: self.props.clone(),
-            payload: None,
             src: self.src,
-            other,
+            ..ser_err
         }
     }
 
@@ -205,19 +273,27 @@ impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
         PLD: Serialize,
         SRC: Serialize,
     {
-        let fmt = Fmt(&self);
-        let other = str_specs
-            .into_iter()
-            .map(|spec| fmt.speced_string_tuple(&spec))
-            .collect::<BTreeMap<&'static str, String>>();
+        let ser_err = self.ser_error(&str_specs);
         SerError {
-            kind_id: self.kind_id,
-            msg: self.msg.clone(),
-            tag: self.tag,
-            props: self.props.clone(),
             payload: Some(self.payload),
             src: self.src,
-            other,
+            ..ser_err
+        }
+    }
+
+    /// Same as [`Self::into_sererror_with_payload_src`] but with the string specs given as a slice, the payload
+    /// replaced by `payload`, and the source omitted unless `keep_src` is `true`.
+    pub fn into_sererror_speced<P>(
+        self,
+        str_specs: &[StringSpec],
+        payload: Option<P>,
+        keep_src: bool,
+    ) -> SerError<P, SRC> {
+        let ser_err = self.ser_error(str_specs);
+        SerError {
+            payload,
+            src: if keep_src { self.src } else { None },
+            ..ser_err
         }
     }
 }
@@ -259,8 +335,7 @@ impl<SRC: SendSyncStaticError> Error<BoxPayload, SRC> {
                     props: self.props,
                     payload,
                     src: self.src,
-                    backtrace: self.backtrace,
-                    ref_id: self.ref_id,
+                    meta: self.meta,
                 }),
                 Err(_) => unreachable!("downcast previously confirmed"),
             }
@@ -297,6 +372,7 @@ impl<SRC: SendSyncStaticError> Error<BoxPayload, SRC> {
     /// If the payload is of type `T`, returns `Err(f(error_ext))` where `error_ext` is
     /// `self` with the `Box dyn` payload replaced by a `Box<T>`; otherwise, returns `Ok(self)`.
     /// This unusual signature facilitates chaining of calls of this method for different types.
+    /// To dispatch on the error's kind instead, see [`match_kind!`](crate::match_kind).
     ///
     /// # Example
     /// ```
@@ -353,6 +429,7 @@ impl<PLD: Payload> Error<PLD, StdBoxError> {
         if self.src_is::<T>()
             || TypeId::of::<T>() == TypeId::of::<NullError>() && self.src.is_none()
         {
+            let src_type_name = self.src.as_ref().map(StdBoxError::type_name);
             let src = if self.src.is_none() {
                 None
             } else {
@@ -363,6 +440,8 @@ impl<PLD: Payload> Error<PLD, StdBoxError> {
                 }
             };
 
+            let mut meta = self.meta;
+            meta.src_type_name = src_type_name;
             Ok(Error {
                 kind_id: self.kind_id,
                 msg: self.msg,
@@ -370,8 +449,7 @@ impl<PLD: Payload> Error<PLD, StdBoxError> {
                 props: self.props,
                 payload: self.payload,
                 src,
-                backtrace: self.backtrace,
-                ref_id: self.ref_id,
+                meta,
             })
         } else {
             Err(self)
@@ -448,8 +526,7 @@ impl Error {
                 props: err.props,
                 payload: BoxPayload::new(*err.payload),
                 src: err.src,
-                backtrace: err.backtrace,
-                ref_id: err.ref_id,
+                meta: err.meta,
             }),
         }
     }
@@ -495,12 +572,78 @@ impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
 
 // endregion:   --- Error downcast methods
 
+//===========================
+// region:      --- Typed errors
+
+/// Statically typed form of the errors of kind `K`, as returned by the `typed_error*` constructors of
+/// [`FullKind`](super::FullKind) and by [`Error::downcast_payload_src_for_kind`].
+pub type TypedError<K> = Error<Box<<K as KindTypeInfo>::Pld>, Box<<K as KindTypeInfo>::Src>>;
+
+impl<PLD: Payload, SRC: SendSyncStaticError> Error<Box<PLD>, Box<SRC>> {
+    /// Converts `self` into the type-erased [`Error`] without reallocating the payload or source.
+    /// Inverse of [`Error::downcast_payload_src`].
+    pub fn erase(self) -> Error {
+        let mut meta = self.meta;
+        meta.src_type_name = None;
+        Error {
+            kind_id: self.kind_id,
+            msg: self.msg,
+            tag: self.tag,
+            props: self.props,
+            payload: BoxPayload(self.payload),
+            src: self.src.map(StdBoxError::from_box),
+            meta,
+        }
+    }
+}
+
+/// Boxes the erased form of the error, so that its kind, tag, and props are included in [`ser_source_chain`],
+/// while keeping the name of its typed error type. The boxed error downcasts to [`Error`].
+impl<PLD: Payload, SRC: SendSyncStaticError> From<Error<Box<PLD>, Box<SRC>>> for StdBoxError {
+    fn from(value: Error<Box<PLD>, Box<SRC>>) -> Self {
+        Self(
+            Box::new(value.erase()),
+            type_name::<Error<Box<PLD>, Box<SRC>>>,
+        )
+    }
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> From<Error<Box<PLD>, Box<SRC>>> for Error {
+    fn from(value: Error<Box<PLD>, Box<SRC>>) -> Self {
+        value.erase()
+    }
+}
+
+// endregion:   --- Typed errors
+
 //===========================
 // region:      --- Error trait impls
 
+/// The alternate form (`{:#?}`) is the [`Report`](super::Report) of `self`.
+impl<PLD: Payload, SRC: SendSyncStaticError> Debug for Error<PLD, SRC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return Display::fmt(&self.report(), f);
+        }
+        f.debug_struct("Error")
+            .field("kind_id", &self.kind_id)
+            .field("msg", &self.msg)
+            .field("tag", &self.tag)
+            .field("props", &self.props)
+            .field("payload", &self.payload)
+            .field("src", &self.src)
+            .field("backtrace", &self.meta.backtrace)
+            .field("ref_id", &self.meta.ref_id)
+            .field("trail", &self.meta.trail)
+            .field("location", &self.meta.location)
+            .field("created_at", &self.meta.created_at)
+            .finish()
+    }
+}
+
 impl<PLD: Payload, SRC: SendSyncStaticError> Display for Error<PLD, SRC> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.msg)
+        f.write_str(&self.interpolated_msg())
     }
 }
 
@@ -515,7 +658,19 @@ impl<PLD: Payload, SRC: SendSyncStaticError> StdError for Error<PLD, SRC> {
 
 impl<PLD: Payload, SRC: SendSyncStaticError> WithBacktrace for Error<PLD, SRC> {
     fn backtrace(&self) -> &Backtrace {
-        Self::backtrace(&self)
+        Self::backtrace(self)
+    }
+
+    fn trail(&self) -> Option<&Trail> {
+        Some(&self.meta.trail)
+    }
+
+    fn location(&self) -> Option<&'static Location<'static>> {
+        Some(self.meta.location)
+    }
+
+    fn created_at(&self) -> Option<SystemTime> {
+        Some(self.meta.created_at)
     }
 }
 
@@ -524,24 +679,38 @@ impl<PLD: Payload, SRC: SendSyncStaticError> WithBacktrace for Error<PLD, SRC> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::context::{Locale, LocaleCtx, LocalizedMsg};
     use crate::{
-        error::{recursive_msg, swap_result, BacktraceSpec, FullKind, ReverseResult, TrivialError},
+        error::{
+            recursive_msg, set_redaction_policy, swap_result, BacktraceSpec, FullKind,
+            RedactionPolicy, ReverseResult, TrivialError, UNEXPECTED_ERROR,
+        },
         validation::validc::VALIDATION_ERROR,
     };
-    use std::any::Any;
+    use std::{
+        any::Any,
+        ops::Deref,
+        time::{Duration, UNIX_EPOCH},
+    };
     use valid::{constraint::Bound, Validate, ValidationError};
 
     #[derive(Debug, Clone, PartialEq)]
     struct Pld(String);
 
-    static BAR_TAG: Tag = Tag("BAR");
+    static BAR_TAG: Tag = Tag::new("BAR");
 
-    static BAR_ERROR: FullKind<Pld, 2, TrivialError> =
-        FullKind::new_with_payload("BAR_ERROR", Some("bar message: {abc}, {!email}"), &BAR_TAG)
-            .with_prop_names(["abc", "!email"])
-            .with_backtrace(BacktraceSpec::Env);
+    static BAR_ERROR: FullKind<Pld, 2, TrivialError> = FullKind::new_with_props(
+        "BAR_ERROR",
+        Some("bar message: {abc}, {!email}"),
+        &BAR_TAG,
+        ["abc", "!email"],
+    )
+    .with_backtrace(BacktraceSpec::Env);
 
+    /// The tests that use this function reveal the protected `!email` prop, which would otherwise be
+    /// masked in release builds. No test relies on a different global redaction policy.
     fn make_payload_src_error_tuple() -> (Pld, TrivialError, Error) {
+        set_redaction_policy(RedactionPolicy::Reveal);
         let pld = Pld("bar-payload".into());
         let src = TrivialError("dummy");
         let err = BAR_ERROR.error_with_values_payload_src(
@@ -552,12 +721,76 @@ mod test {
         (pld, src, err)
     }
 
+    #[test]
+    fn test_interpolated_msg() {
+        let (_, _, err) = make_payload_src_error_tuple();
+
+        assert_eq!(err.msg(), "bar message: {abc}, {!email}");
+        assert_eq!(
+            err.interpolated_msg(),
+            "bar message: hi there, bar@example.com"
+        );
+
+        let ser_err = err.to_sererror_no_payload_src([]);
+        assert_eq!(ser_err.msg(), err.to_string());
+        assert_eq!(ser_err.to_string(), err.to_string());
+    }
+
+    #[derive(Debug)]
+    struct PtCtx;
+
+    impl LocalizedMsg for PtCtx {
+        fn localized_msg(kind: &str, locale: impl Deref<Target = str>) -> Option<&str> {
+            match (kind, locale.as_ref()) {
+                ("BAR_ERROR", "pt-BR") => Some("mensagem bar: {abc}, {!email}"),
+                _ => None,
+            }
+        }
+    }
+
+    impl Locale for PtCtx {
+        fn locale() -> impl Deref<Target = str> {
+            "pt-BR"
+        }
+    }
+
+    impl LocaleCtx for PtCtx {
+        type Locale = PtCtx;
+    }
+
+    impl ErrCtx for PtCtx {
+        type LocalizedMsg = PtCtx;
+    }
+
+    #[test]
+    fn test_localized_msg() {
+        let (_, _, err) = make_payload_src_error_tuple();
+
+        assert_eq!(
+            err.localized_msg::<PtCtx>(),
+            "mensagem bar: hi there, bar@example.com"
+        );
+        assert_eq!(err.localized_msg::<()>(), err.to_string());
+
+        let ser_err = err.to_sererror_no_payload_src([]);
+        assert_eq!(
+            ser_err.localized::<PtCtx>().msg(),
+            "mensagem bar: hi there, bar@example.com"
+        );
+
+        let unlocalized = TrivialError("unlocalized");
+        let ser_err = UNEXPECTED_ERROR
+            .error_with_src(StdBoxError::new(unlocalized))
+            .to_sererror_no_payload_src([]);
+        assert_eq!(ser_err.localized::<PtCtx>().msg(), "UNEXPECTED_ERROR");
+    }
+
     #[test]
     fn test_downcast_payload_ref() {
         let (payload, _, err) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), BAR_ERROR.msg());
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         let payload_ext = err.downcast_payload_ref::<Pld>().unwrap();
         assert_eq!(&payload, payload_ext);
@@ -568,7 +801,7 @@ mod test {
         let (payload, _, err) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), BAR_ERROR.msg());
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         let err_ext = err.downcast_payload::<Pld>().unwrap();
         assert_eq!(&payload, err_ext.payload().as_ref());
@@ -579,7 +812,7 @@ mod test {
         let (payload, _, err) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), "bar message: {abc}, {!email}");
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         let res = swap_result(|| -> ReverseResult<()> {
             err.with_downcast_payload::<String, _>(|_| unreachable!())?
@@ -596,7 +829,7 @@ mod test {
         let (_, src, err) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), BAR_ERROR.msg());
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         println!("err={:?}", err);
         println!("recursive_msg={}", recursive_msg(&err));
@@ -613,7 +846,7 @@ mod test {
         println!("src.type_id()={:?}", Any::type_id(&src));
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), "bar message: {abc}, {!email}");
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         let res = err.downcast_payload::<Pld>();
         match res {
@@ -636,7 +869,7 @@ mod test {
         let (_, _, err1) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), "bar message: {abc}, {!email}");
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         if err.payload_is::<String>() {
             unreachable!()
@@ -657,7 +890,7 @@ mod test {
         let (_, _, err1) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), "bar message: {abc}, {!email}");
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         let res = swap_result(|| -> ReverseResult<()> {
             err.with_downcast_payload::<String, _>(|_| unreachable!())?
@@ -701,7 +934,7 @@ mod test {
         let (_, _, err1) = make_payload_src_error_tuple();
 
         assert!(err.has_kind(BAR_ERROR.kind_id()));
-        assert_eq!(err.to_string(), "bar message: {abc}, {!email}");
+        assert_eq!(err.to_string(), "bar message: hi there, bar@example.com");
 
         err.chained_map(
             |err| {
@@ -717,4 +950,41 @@ mod test {
             |_err| unreachable!("fallback shouldn't execute"),
         )
     }
+
+    #[test]
+    fn test_location_and_created_at() {
+        let before = SystemTime::now();
+        let line = line!() + 1;
+        let err = UNEXPECTED_ERROR.error_with_src(StdBoxError::new(TrivialError("x")));
+        assert!(err.created_at() >= before);
+        assert_eq!(err.location().file(), file!());
+        assert_eq!(err.location().line(), line);
+
+        let fmt = Fmt(&err);
+        assert_eq!(
+            fmt.speced_string(&StringSpec::Location),
+            err.location().to_string()
+        );
+        let created_at = fmt.speced_string(&StringSpec::CreatedAt);
+        assert_eq!(created_at, rfc3339_utc(err.created_at()));
+        assert_eq!(
+            rfc3339_utc(UNIX_EPOCH + Duration::from_millis(1_715_938_867_042)),
+            "2024-05-17T09:41:07.042Z"
+        );
+
+        let ser_err = err.to_sererror_no_payload_src([]);
+        assert_eq!(ser_err.location(), None);
+        assert_eq!(ser_err.created_at(), None);
+        let json = serde_json::to_value(&ser_err).unwrap();
+        assert!(json.get("location").is_none());
+        assert!(json.get("created_at").is_none());
+
+        let ser_err = err.to_sererror_no_payload_src([StringSpec::Location, StringSpec::CreatedAt]);
+        assert_eq!(
+            ser_err.location(),
+            Some(err.location().to_string().as_str())
+        );
+        assert_eq!(ser_err.created_at(), Some(created_at.as_str()));
+        assert!(ser_err.other().is_empty());
+    }
 }
@@ -1,8 +1,10 @@
 use super::{
-    ref_id_u32_hex_lower, BacktraceSpec, Error, KindId, KindTypeInfo, NullError, Payload, Props,
-    SendSyncStaticError, StdBoxError, Tag,
+    backtrace_policy::policy_backtrace, new_ref_id, BacktraceSpec, BoxPayload, Error, ErrorMeta,
+    KindId, KindInfo, KindTypeInfo, NullError, Payload, PropValue, Props, SendSyncStaticError,
+    StdBoxError, Tag, TypedError,
 };
-use std::backtrace::Backtrace;
+use crate::string;
+use std::any::type_name;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -33,44 +35,57 @@ pub type PayloadKind<PLD, SRC = NullError> = FullKind<PLD, 0, SRC>;
 // region:      --- Kind constructors
 
 impl<SRC: SendSyncStaticError> BasicKind<SRC> {
+    /// Creates a kind without props. If `msg` has `{name}` placeholders, they must be declared with
+    /// [`FullKind::with_prop_names`] or [`FullKind::with_props`], which check them during const evaluation.
     pub const fn new(name: &'static str, msg: Option<&'static str>, tag: &'static Tag) -> Self {
-        Self {
-            kind_id: KindId(name),
-            msg,
-            tag,
-            prop_names: [],
-            backtrace_spec: BacktraceSpec::No,
-            has_ref_id: false,
-            _pld: PhantomData,
-            _src: PhantomData,
-        }
+        Self::new_priv(name, msg, tag, [])
     }
 }
 
 impl<PLD: Payload, SRC: SendSyncStaticError> PayloadKind<PLD, SRC> {
+    /// Creates a kind with a payload and without props. If `msg` has `{name}` placeholders, they must be
+    /// declared as in [`BasicKind::new`].
     pub const fn new_with_payload(
         name: &'static str,
         msg: Option<&'static str>,
         tag: &'static Tag,
     ) -> Self {
-        Self {
-            kind_id: KindId(name),
-            msg,
-            tag,
-            prop_names: [],
-            backtrace_spec: BacktraceSpec::No,
-            has_ref_id: false,
-            _pld: PhantomData,
-            _src: PhantomData,
-        }
+        Self::new_priv(name, msg, tag, [])
+    }
+}
+
+/// Panics if the `{name}` placeholders in `msg` are not exactly the names in `prop_names`. When called
+/// in the definition of a static kind, this causes the build to fail.
+pub(crate) const fn check_prop_names(msg: Option<&str>, prop_names: &[&str]) {
+    let msg = match msg {
+        Some(msg) => msg,
+        None => "",
+    };
+    if !string::placeholders_declared(msg, prop_names) {
+        panic!("error kind message has a placeholder that is not a declared prop name");
+    }
+    if !string::names_used(msg, prop_names) {
+        panic!("error kind has a prop name that is not a placeholder in its message");
     }
 }
 
 impl<PLD: Payload, SRC: SendSyncStaticError> FullKind<PLD, 0, SRC> {
+    /// Sets the kind's prop names. Fails const evaluation, and therefore the build for static kinds,
+    /// if the `{name}` placeholders in the kind's message are not exactly `prop_names`.
+    ///
+    /// ```compile_fail
+    /// use foa::error::{BasicKind, PropsKind, Tag};
+    ///
+    /// static FOO_TAG: Tag = Tag::new("FOO");
+    ///
+    /// static FOO_ERROR: PropsKind<1> =
+    ///     BasicKind::new("FOO_ERROR", Some("foo {xyz}"), &FOO_TAG).with_prop_names(["abc"]);
+    /// ```
     pub const fn with_prop_names<const ARITY: usize>(
         self,
         prop_names: [&'static str; ARITY],
     ) -> FullKind<PLD, ARITY, SRC> {
+        check_prop_names(self.msg, &prop_names);
         FullKind {
             kind_id: self.kind_id,
             msg: self.msg,
@@ -85,6 +100,49 @@ impl<PLD: Payload, SRC: SendSyncStaticError> FullKind<PLD, 0, SRC> {
 }
 
 impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, ARITY, SRC> {
+    /// Creates a kind with props named `prop_names`. Fails const evaluation, and therefore the build for
+    /// static kinds, if the `{name}` placeholders in `msg` are not exactly `prop_names`.
+    ///
+    /// ```compile_fail
+    /// use foa::error::{PropsKind, Tag};
+    ///
+    /// static FOO_TAG: Tag = Tag::new("FOO");
+    ///
+    /// static FOO_ERROR: PropsKind<1> =
+    ///     PropsKind::new_with_props("FOO_ERROR", Some("foo {xyz}"), &FOO_TAG, ["abc"]);
+    /// ```
+    pub const fn new_with_props(
+        name: &'static str,
+        msg: Option<&'static str>,
+        tag: &'static Tag,
+        prop_names: [&'static str; ARITY],
+    ) -> Self {
+        check_prop_names(msg, &prop_names);
+        Self::new_priv(name, msg, tag, prop_names)
+    }
+
+    /// Creates a kind without checking `msg`'s placeholders against `prop_names`.
+    pub(super) const fn new_priv(
+        name: &'static str,
+        msg: Option<&'static str>,
+        tag: &'static Tag,
+        prop_names: [&'static str; ARITY],
+    ) -> Self {
+        Self {
+            kind_id: KindId(name),
+            msg,
+            tag,
+            prop_names,
+            backtrace_spec: BacktraceSpec::No,
+            has_ref_id: false,
+            _pld: PhantomData,
+            _src: PhantomData,
+        }
+    }
+}
+
+impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, ARITY, SRC> {
+    /// Sets the backtrace spec of the kind, which can be overridden at runtime (see [`BacktracePolicy`](super::BacktracePolicy)).
     pub const fn with_backtrace(self, backtrace_spec: BacktraceSpec) -> Self {
         Self {
             backtrace_spec,
@@ -168,97 +226,139 @@ impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, A
 // region:      --- Error constructors
 
 impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, ARITY, SRC> {
-    fn error_priv(
+    fn pairs<V: Into<PropValue>>(&self, values: [V; ARITY]) -> Vec<(String, PropValue)> {
+        self.prop_names
+            .into_iter()
+            .zip(values)
+            .map(|(name, value)| (name.to_owned(), value.into()))
+            .collect()
+    }
+
+    #[track_caller]
+    fn error_priv<V: Into<PropValue>>(
         &'static self,
-        values: [&str; ARITY],
+        values: [V; ARITY],
         payload: PLD,
         source: Option<StdBoxError>,
     ) -> Error {
-        let pairs = self
-            .prop_names
-            .into_iter()
-            .zip(values)
-            .map(|(name, value)| (name.to_owned(), value.to_owned()))
-            .collect::<Vec<_>>();
+        self.error_with_pairs_priv(self.pairs(values), payload, source)
+    }
+
+    #[track_caller]
+    pub(super) fn error_with_pairs_priv(
+        &'static self,
+        pairs: Vec<(String, PropValue)>,
+        payload: PLD,
+        source: Option<StdBoxError>,
+    ) -> Error {
+        self.new_error(pairs, BoxPayload::new(payload), source)
+    }
+
+    #[track_caller]
+    fn typed_error_priv<V: Into<PropValue>>(
+        &'static self,
+        values: [V; ARITY],
+        payload: PLD,
+        source: Option<SRC>,
+    ) -> Error<Box<PLD>, Box<SRC>> {
+        let mut err = self.new_error(self.pairs(values), Box::new(payload), source.map(Box::new));
+        err.meta.src_type_name = Some(type_name::<SRC>());
+        err
+    }
+
+    #[track_caller]
+    fn new_error<P, S>(
+        &'static self,
+        pairs: Vec<(String, PropValue)>,
+        payload: P,
+        source: Option<S>,
+    ) -> Error<P, S> {
         let props = Props {
             pairs,
             protected: false,
         };
-        let msg = match self.msg {
-            Some(msg) => msg,
-            None => self.kind_id.0,
-        };
-        let backtrace = match self.backtrace_spec {
-            BacktraceSpec::Yes => Backtrace::force_capture(),
-            BacktraceSpec::No => Backtrace::disabled(),
-            BacktraceSpec::Env => Backtrace::capture(),
-        };
+        let backtrace = policy_backtrace(&self.kind_id, self.tag, self.backtrace_spec);
 
         let ref_id = if self.has_ref_id {
-            Some(ref_id_u32_hex_lower())
+            Some(new_ref_id())
         } else {
             None
         };
 
-        Error::new(
-            self.kind_id(),
-            msg.into(),
-            self.tag,
+        Error {
+            kind_id: self.kind_id(),
+            msg: self.msg().into(),
+            tag: self.tag,
             props,
             payload,
-            source,
-            backtrace,
-            ref_id,
-        )
+            src: source,
+            meta: Box::new(ErrorMeta::new(backtrace, ref_id)),
+        }
     }
 }
 
 impl BasicKind {
+    #[track_caller]
     pub fn error(&'static self) -> Error {
-        self.error_priv([], (), None)
+        self.error_priv::<PropValue>([], (), None)
     }
 }
 
 impl<SRC: SendSyncStaticError> BasicKind<SRC> {
+    #[track_caller]
     pub fn error_with_src(&'static self, source: SRC) -> Error {
-        self.error_priv([], (), Some(StdBoxError::new(source)))
+        self.error_priv::<PropValue>([], (), Some(StdBoxError::new(source)))
     }
 }
 
 impl<const ARITY: usize> PropsKind<ARITY> {
-    pub fn error_with_values(&'static self, values: [&str; ARITY]) -> Error {
+    #[track_caller]
+    pub fn error_with_values(&'static self, values: [impl Into<PropValue>; ARITY]) -> Error {
         self.error_priv(values, (), None)
     }
 }
 
 impl<const ARITY: usize, SRC: SendSyncStaticError> PropsKind<ARITY, SRC> {
-    pub fn error_with_values_src(&'static self, values: [&str; ARITY], source: SRC) -> Error {
+    #[track_caller]
+    pub fn error_with_values_src(
+        &'static self,
+        values: [impl Into<PropValue>; ARITY],
+        source: SRC,
+    ) -> Error {
         self.error_priv(values, (), Some(StdBoxError::new(source)))
     }
 }
 
 impl<PLD: Payload> FullKind<PLD, 0> {
+    #[track_caller]
     pub fn error_with_payload(&'static self, payload: PLD) -> Error {
-        self.error_priv([], payload, None)
+        self.error_priv::<PropValue>([], payload, None)
     }
 }
 
 impl<PLD: Payload, SRC: SendSyncStaticError> FullKind<PLD, 0, SRC> {
+    #[track_caller]
     pub fn error_with_payload_src(&'static self, payload: PLD, source: SRC) -> Error {
-        self.error_priv([], payload, Some(StdBoxError::new(source)))
+        self.error_priv::<PropValue>([], payload, Some(StdBoxError::new(source)))
     }
 }
 
 impl<PLD: Payload, const ARITY: usize> FullKind<PLD, ARITY> {
-    pub fn error_with_values_payload(&'static self, values: [&str; ARITY], payload: PLD) -> Error {
+    #[track_caller]
+    pub fn error_with_values_payload(
+        &'static self,
+        values: [impl Into<PropValue>; ARITY],
+        payload: PLD,
+    ) -> Error {
         self.error_priv(values, payload, None)
     }
 }
 
 impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, ARITY, SRC> {
+    #[track_caller]
     pub fn error_with_values_payload_src(
         &'static self,
-        values: [&str; ARITY],
+        values: [impl Into<PropValue>; ARITY],
         payload: PLD,
         source: SRC,
     ) -> Error {
@@ -268,6 +368,90 @@ impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, A
 
 // endregion:   --- Error constructors
 
+//===========================
+// region:      --- Typed error constructors
+
+// Same as the error constructors but return statically typed errors, which can be converted into [`Error`]
+// with [`Error::erase`].
+
+impl BasicKind {
+    #[track_caller]
+    pub fn typed_error(&'static self) -> TypedError<Self> {
+        self.typed_error_priv::<PropValue>([], (), None)
+    }
+}
+
+impl<SRC: SendSyncStaticError> BasicKind<SRC> {
+    #[track_caller]
+    pub fn typed_error_with_src(&'static self, source: SRC) -> TypedError<Self> {
+        self.typed_error_priv::<PropValue>([], (), Some(source))
+    }
+}
+
+impl<const ARITY: usize> PropsKind<ARITY> {
+    #[track_caller]
+    pub fn typed_error_with_values(
+        &'static self,
+        values: [impl Into<PropValue>; ARITY],
+    ) -> TypedError<Self> {
+        self.typed_error_priv(values, (), None)
+    }
+}
+
+impl<const ARITY: usize, SRC: SendSyncStaticError> PropsKind<ARITY, SRC> {
+    #[track_caller]
+    pub fn typed_error_with_values_src(
+        &'static self,
+        values: [impl Into<PropValue>; ARITY],
+        source: SRC,
+    ) -> TypedError<Self> {
+        self.typed_error_priv(values, (), Some(source))
+    }
+}
+
+impl<PLD: Payload> FullKind<PLD, 0> {
+    #[track_caller]
+    pub fn typed_error_with_payload(&'static self, payload: PLD) -> TypedError<Self> {
+        self.typed_error_priv::<PropValue>([], payload, None)
+    }
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> FullKind<PLD, 0, SRC> {
+    #[track_caller]
+    pub fn typed_error_with_payload_src(
+        &'static self,
+        payload: PLD,
+        source: SRC,
+    ) -> TypedError<Self> {
+        self.typed_error_priv::<PropValue>([], payload, Some(source))
+    }
+}
+
+impl<PLD: Payload, const ARITY: usize> FullKind<PLD, ARITY> {
+    #[track_caller]
+    pub fn typed_error_with_values_payload(
+        &'static self,
+        values: [impl Into<PropValue>; ARITY],
+        payload: PLD,
+    ) -> TypedError<Self> {
+        self.typed_error_priv(values, payload, None)
+    }
+}
+
+impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> FullKind<PLD, ARITY, SRC> {
+    #[track_caller]
+    pub fn typed_error_with_values_payload_src(
+        &'static self,
+        values: [impl Into<PropValue>; ARITY],
+        payload: PLD,
+        source: SRC,
+    ) -> TypedError<Self> {
+        self.typed_error_priv(values, payload, Some(source))
+    }
+}
+
+// endregion:   --- Typed error constructors
+
 //===========================
 // region:      --- impl KindTypeInfo
 
@@ -280,12 +464,42 @@ impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> KindTypeInfo
 
 // endregion:   --- impl KindTypeInfo
 
+//===========================
+// region:      --- impl KindInfo
+
+impl<PLD: Payload, const ARITY: usize, SRC: SendSyncStaticError> KindInfo
+    for FullKind<PLD, ARITY, SRC>
+{
+    fn kind_id(&self) -> &KindId {
+        &self.kind_id
+    }
+
+    fn tag(&self) -> &'static Tag {
+        self.tag
+    }
+
+    fn msg(&self) -> &'static str {
+        Self::msg(self)
+    }
+
+    fn prop_names(&self) -> &[&'static str] {
+        &self.prop_names
+    }
+
+    fn has_ref_id(&self) -> bool {
+        self.has_ref_id
+    }
+}
+
+// endregion:   --- impl KindInfo
+
 #[cfg(test)]
 mod test_props_kind {
-    use super::{BasicKind, PropsKind};
+    use super::{BasicKind, PropValue, Props, PropsKind};
     use crate::error::{BacktraceSpec, Tag};
+    use crate::string;
 
-    static FOO_TAG: Tag = Tag("FOO");
+    static FOO_TAG: Tag = Tag::new("FOO");
 
     static FOO_ERROR: PropsKind<1> =
         BasicKind::new("FOO_ERROR", Some("foo message: {xyz}"), &FOO_TAG)
@@ -296,7 +510,82 @@ mod test_props_kind {
     fn test() {
         let err = FOO_ERROR.error_with_values(["hi there!"]);
         assert!(err.has_kind(FOO_ERROR.kind_id()));
-        assert_eq!(err.to_string(), "foo message: {xyz}");
+        assert_eq!(err.to_string(), "foo message: hi there!");
+    }
+
+    static TYPED_ERROR: PropsKind<4> = PropsKind::new_with_props(
+        "TYPED_ERROR",
+        Some("retry {retries} of {amount} for {name} with {ids}"),
+        &FOO_TAG,
+        ["retries", "amount", "name", "ids"],
+    );
+
+    #[test]
+    fn test_typed_values() {
+        let err = TYPED_ERROR.error_with_values([
+            PropValue::from(3),
+            12.5.into(),
+            "foo".into(),
+            serde_json::json!([1, 2]).into(),
+        ]);
+        assert_eq!(err.to_string(), "retry 3 of 12.5 for foo with [1,2]");
+
+        let props = err.props();
+        assert_eq!(props.prop_i64("retries"), Some(3));
+        assert_eq!(props.prop_f64("amount"), Some(12.5));
+        assert_eq!(props.prop_str("name"), Some("foo"));
+        assert_eq!(props.prop_str("retries"), None);
+        assert_eq!(props.prop_value("retries").as_deref(), Some("3"));
+        assert_eq!(props.prop_value("ids").as_deref(), Some("[1,2]"));
+        assert_eq!(props.prop_json("ids"), Some(&serde_json::json!([1, 2])));
+
+        let json = serde_json::to_value(props).unwrap();
+        assert_eq!(
+            json["pairs"],
+            serde_json::json!([
+                ["retries", 3],
+                ["amount", 12.5],
+                ["name", "foo"],
+                ["ids", [1, 2]]
+            ])
+        );
+        let deser: Props = serde_json::from_value(json).unwrap();
+        assert_eq!(&deser, props);
+    }
+
+    #[test]
+    fn test_unsigned_values() {
+        let values = [
+            PropValue::from(u64::MAX),
+            PropValue::from(7usize),
+            PropValue::from(-(1i128 << 100)),
+            PropValue::from(u128::from(u64::MAX) - 1),
+        ];
+        assert_eq!(values[0], PropValue::UInt(u64::MAX));
+        assert_eq!(values[1], PropValue::Int(7));
+        assert_eq!(values[2], PropValue::Str((-(1i128 << 100)).to_string()));
+        assert_eq!(values[3], PropValue::UInt(u64::MAX - 1));
+        assert_eq!(values[0].as_u64(), Some(u64::MAX));
+        assert_eq!(values[1].as_u64(), Some(7));
+
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(
+            json,
+            format!("[{},7,\"{}\",{}]", u64::MAX, -(1i128 << 100), u64::MAX - 1)
+        );
+        let deser: Vec<PropValue> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deser, values);
+    }
+
+    #[test]
+    fn test_placeholder_checks() {
+        let msg = "foo {abc} {} {!email} {abc}";
+        assert!(string::placeholders_declared(msg, &["abc", "!email"]));
+        assert!(string::names_used(msg, &["abc", "!email"]));
+        assert!(!string::placeholders_declared(msg, &["abc"]));
+        assert!(!string::names_used(msg, &["abc", "xyz"]));
+        assert!(string::placeholders_declared("foo {abc", &[]));
+        assert!(!string::names_used("foo", &["abc"]));
     }
 }
 
@@ -305,7 +594,7 @@ mod test_basic_kind {
     use super::BasicKind;
     use crate::error::{BacktraceSpec, Tag};
 
-    static FOO_TAG: Tag = Tag("FOO");
+    static FOO_TAG: Tag = Tag::new("FOO");
 
     static FOO_ERROR: BasicKind =
         BasicKind::new("FOO_ERROR", None, &FOO_TAG).with_backtrace(BacktraceSpec::Env);
@@ -320,10 +609,11 @@ mod test_basic_kind {
 
 #[cfg(test)]
 mod test_payload_kind {
-    use super::PayloadKind;
-    use crate::error::{BacktraceSpec, Tag};
+    use super::{FullKind, PayloadKind};
+    use crate::error::{BacktraceSpec, Tag, TrivialError};
+    use crate::Error;
 
-    static FOO_TAG: Tag = Tag("FOO");
+    static FOO_TAG: Tag = Tag::new("FOO");
 
     static FOO_ERROR: PayloadKind<String> =
         PayloadKind::new_with_payload("FOO_ERROR", None, &FOO_TAG)
@@ -335,4 +625,31 @@ mod test_payload_kind {
         assert!(err.has_kind(FOO_ERROR.kind_id()));
         assert_eq!(err.to_string(), "FOO_ERROR".to_string());
     }
+
+    static BAR_ERROR: FullKind<String, 1, TrivialError> =
+        FullKind::new_with_props("BAR_ERROR", Some("bar {abc}"), &FOO_TAG, ["abc"]);
+
+    #[test]
+    fn test_typed() {
+        let line = line!() + 1;
+        let err = BAR_ERROR.typed_error_with_values_payload_src(
+            ["x"],
+            "dummy payload".to_owned(),
+            TrivialError("dummy"),
+        );
+        assert_eq!(err.payload().as_str(), "dummy payload");
+        assert_eq!(err.src().unwrap().0, "dummy");
+        assert_eq!(err.to_string(), "bar x");
+        assert_eq!(err.location().line(), line);
+
+        let err: Error = err.erase();
+        assert!(err.has_kind(BAR_ERROR.kind_id()));
+        assert_eq!(err.location().line(), line);
+        let err = err.downcast_payload_src_for_kind(&BAR_ERROR).unwrap();
+        assert_eq!(err.payload().as_str(), "dummy payload");
+
+        let err: Error = FOO_ERROR.typed_error_with_payload("x".to_owned()).into();
+        assert!(err.src().is_none());
+        assert_eq!(err.downcast_payload_ref::<String>().unwrap(), "x");
+    }
 }
@@ -0,0 +1,128 @@
+//===========================
+// region:      --- match_kind
+
+/// Dispatches on the kind of a [`Error`](crate::Error), like a `match` on its kind id, for a list of
+/// [`FullKind`](crate::error::FullKind) statics (or other statics that implement
+/// [`KindTypeInfo`](crate::error::KindTypeInfo) and have a `kind_id` method).
+///
+/// Each `KIND => |e| body` arm binds `e` to the error downcast to the kind's payload and source types,
+/// i.e., an `Error<Box<KIND::Pld>, Box<KIND::Src>>`. The last arm, `_ => |e| body`, is required and binds
+/// `e` to the error as is. It is also taken if the error has a listed kind but its payload or source are not
+/// of the kind's types, e.g., because it was not created from the kind. Arms are tried in order.
+///
+/// Arm bodies are not closures: `return` and `?` in them apply to the enclosing function.
+///
+/// ```
+/// use foa::{error::{FullKind, Tag, TrivialError}, match_kind, Error};
+///
+/// static FOO_TAG: Tag = Tag::new("FOO");
+///
+/// static FOO_ERROR: FullKind<u32, 0, TrivialError> =
+///     FullKind::new_with_payload("FOO_ERROR", None, &FOO_TAG);
+///
+/// static BAR_ERROR: FullKind<String, 0> = FullKind::new_with_payload("BAR_ERROR", None, &FOO_TAG);
+///
+/// fn describe(err: Error) -> String {
+///     match_kind!(err, {
+///         FOO_ERROR => |e| format!("foo {} caused by {}", e.payload(), e.src().unwrap()),
+///         BAR_ERROR => |e| format!("bar {}", e.payload()),
+///         _ => |e| format!("other {}", e.kind_id().0),
+///     })
+/// }
+///
+/// let err = FOO_ERROR.error_with_payload_src(42, TrivialError("trivial"));
+/// assert_eq!(describe(err), "foo 42 caused by trivial");
+/// ```
+#[macro_export]
+macro_rules! match_kind {
+    ($err:expr, { $($arms:tt)* }) => {
+        'dispatch: {
+            let err: $crate::Error = $err;
+            $crate::match_kind!(@arms 'dispatch, err, $($arms)*)
+        }
+    };
+
+    (@arms $label:lifetime, $err:ident, _ => |$e:ident| $body:expr $(,)?) => {{
+        let $e = $err;
+        $body
+    }};
+
+    (@arms $label:lifetime, $err:ident, $kind:path => |$e:ident| $body:expr, $($rest:tt)*) => {{
+        let $err = if $err.has_kind($kind.kind_id()) {
+            match $err.downcast_payload_src_for_kind(&$kind) {
+                ::core::result::Result::Ok($e) => {
+                    let res = $body;
+                    break $label res;
+                }
+                ::core::result::Result::Err(err) => err,
+            }
+        } else {
+            $err
+        };
+        $crate::match_kind!(@arms $label, $err, $($rest)*)
+    }};
+
+    (@arms $label:lifetime, $err:ident $(,)?) => {
+        compile_error!("match_kind! requires a final fallback arm `_ => |e| ...`")
+    };
+}
+
+// endregion:   --- match_kind
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::{FullKind, PropsKind, StdBoxError, Tag, TrivialError, UNEXPECTED_ERROR},
+        Error, Result,
+    };
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    #[derive(Debug, PartialEq)]
+    struct Pld(String);
+
+    static PLD_ERROR: FullKind<Pld, 1, TrivialError> =
+        FullKind::new_with_props("DISPATCH_PLD_ERROR", Some("pld {abc}"), &FOO_TAG, ["abc"]);
+
+    static PROPS_ERROR: PropsKind<1> = PropsKind::new_with_props(
+        "DISPATCH_PROPS_ERROR",
+        Some("props {abc}"),
+        &FOO_TAG,
+        ["abc"],
+    );
+
+    fn handle(err: Error) -> Result<String> {
+        let res = match_kind!(err, {
+            PLD_ERROR => |e| format!("{}/{}/{}", e, e.payload().0, e.src().unwrap()),
+            PROPS_ERROR => |e| {
+                if e.props().prop_str("abc") == Some("fail") {
+                    return Err(UNEXPECTED_ERROR.error_with_src(StdBoxError::new(e)));
+                }
+                e.to_string()
+            },
+            _ => |e| format!("fallback {}", e.kind_id().0),
+        });
+        Ok(res)
+    }
+
+    #[test]
+    fn test_match_kind() {
+        let err = PLD_ERROR.error_with_values_payload_src(
+            ["x"],
+            Pld("payload".into()),
+            TrivialError("trivial"),
+        );
+        assert_eq!(handle(err).unwrap(), "pld x/payload/trivial");
+
+        let err = PROPS_ERROR.error_with_values(["y"]);
+        assert_eq!(handle(err).unwrap(), "props y");
+
+        let err = PROPS_ERROR.error_with_values(["fail"]);
+        assert!(handle(err)
+            .unwrap_err()
+            .has_kind(UNEXPECTED_ERROR.kind_id()));
+
+        let err = UNEXPECTED_ERROR.error_with_src(StdBoxError::new(TrivialError("unexpected")));
+        assert_eq!(handle(err).unwrap(), "fallback UNEXPECTED_ERROR");
+    }
+}
@@ -1,4 +1,7 @@
-use super::{BacktraceSpec, BasicKind, Error, KindId, StdBoxError, Tag, UNEXPECTED_TAG};
+use super::{
+    full_kind::check_prop_names, BacktraceSpec, BasicKind, Error, KindId, KindInfo, StdBoxError,
+    Tag, UNEXPECTED_TAG,
+};
 use serde::Serialize;
 use std::fmt::{Debug, Display};
 
@@ -26,6 +29,7 @@ pub struct TransmuterKind {
     kind_id: KindId,
     msg: Option<&'static str>,
     tag: &'static Tag,
+    prop_names: &'static [&'static str],
 }
 
 impl TransmuterKind {
@@ -44,15 +48,61 @@ impl TransmuterKind {
         self.tag
     }
 
+    /// Creates a kind whose message references no props. If `msg` has `{name}` placeholders, they must be
+    /// declared with [`Self::with_prop_names`], which checks them during const evaluation.
     pub const fn new(name: &'static str, msg: Option<&'static str>, tag: &'static Tag) -> Self {
         Self {
             kind_id: KindId(name),
             msg,
             tag,
+            prop_names: &[],
         }
     }
 
+    /// Creates a kind whose message references the props named `prop_names`, expected to be present in
+    /// transmuted errors. Fails const evaluation, and therefore the build for static kinds, if the `{name}`
+    /// placeholders in `msg` are not exactly `prop_names`.
+    pub const fn new_with_props(
+        name: &'static str,
+        msg: Option<&'static str>,
+        tag: &'static Tag,
+        prop_names: &'static [&'static str],
+    ) -> Self {
+        check_prop_names(msg, prop_names);
+        Self {
+            kind_id: KindId(name),
+            msg,
+            tag,
+            prop_names,
+        }
+    }
+
+    /// Declares the names of the props, expected to be present in transmuted errors, that are referenced by the
+    /// kind's message. Fails const evaluation, and therefore the build for static kinds, if the `{name}`
+    /// placeholders in the kind's message are not exactly `prop_names`.
+    ///
+    /// ```compile_fail
+    /// use foa::error::{Tag, TransmuterKind};
+    ///
+    /// static FOO_TAG: Tag = Tag::new("FOO");
+    ///
+    /// static FOO_ERROR: TransmuterKind =
+    ///     TransmuterKind::new("FOO_ERROR", Some("foo {xyz}"), &FOO_TAG).with_prop_names(&["abc"]);
+    /// ```
+    pub const fn with_prop_names(self, prop_names: &'static [&'static str]) -> Self {
+        check_prop_names(self.msg, prop_names);
+        Self { prop_names, ..self }
+    }
+
+    pub const fn prop_names(&self) -> &'static [&'static str] {
+        self.prop_names
+    }
+
+    /// Returns `err` with its kind, message, and tag replaced by those of `self`. `err`'s kind is appended to
+    /// the kind history of its [`Trail`](super::Trail) (see [`Error::was_kind`]).
     pub fn transmute(&'static self, err: Error) -> Error {
+        let mut meta = err.meta;
+        meta.trail.kind_history.push(err.kind_id);
         Error {
             kind_id: &self.kind_id,
             msg: self.msg().into(),
@@ -60,8 +110,29 @@ impl TransmuterKind {
             props: err.props,
             payload: err.payload,
             src: err.src,
-            backtrace: err.backtrace,
-            ref_id: err.ref_id,
+            meta,
         }
     }
 }
+
+impl KindInfo for TransmuterKind {
+    fn kind_id(&self) -> &KindId {
+        &self.kind_id
+    }
+
+    fn tag(&self) -> &'static Tag {
+        self.tag
+    }
+
+    fn msg(&self) -> &'static str {
+        Self::msg(self)
+    }
+
+    fn prop_names(&self) -> &[&'static str] {
+        self.prop_names
+    }
+
+    fn has_ref_id(&self) -> bool {
+        false
+    }
+}
@@ -2,16 +2,27 @@
 // region:      --- modules
 
 mod app_error;
+mod backtrace_frames;
+mod backtrace_policy;
 mod box_error;
 mod core_error;
 mod foa_error;
 mod full_kind;
+mod kind_dispatch;
 mod misc;
+mod multi_error;
 mod payload;
 mod prereq;
+mod redaction;
+mod ref_id;
+mod registry;
+mod report;
 mod serde;
+mod shared_error;
 mod static_str;
+mod struct_kind;
 mod tags;
+mod trail;
 mod utils;
 
 // endregion:   --- modules
@@ -20,16 +31,26 @@ mod utils;
 // region:      --- flattened
 
 pub use app_error::*;
+pub use backtrace_frames::*;
+pub use backtrace_policy::*;
 pub use box_error::*;
 pub use core_error::*;
 pub use foa_error::*;
 pub use full_kind::*;
 pub use misc::*;
+pub use multi_error::*;
 pub use payload::*;
 pub use prereq::*;
+pub use redaction::*;
+pub use ref_id::*;
+pub use registry::*;
+pub use report::*;
 pub use serde::*;
+pub use shared_error::*;
 use static_str::*;
+pub use struct_kind::*;
 pub use tags::*;
+pub use trail::*;
 pub use utils::*;
 
 // endregion:   --- flattened
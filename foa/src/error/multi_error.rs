@@ -0,0 +1,230 @@
+use super::{Error, FullKind, Result, SerError, AGGREGATE_TAG};
+use crate::context::ErrCtx;
+use serde::Serialize;
+use std::{
+    error::Error as StdError,
+    fmt::{Debug, Display},
+};
+
+//===========================
+// region:      --- MULTI_ERROR
+
+/// Kind of errors that aggregate multiple child errors, e.g., from the validation of several fields or from
+/// a batch of invocations. The payload is the [`MultiError`] holding the children.
+pub static MULTI_ERROR: FullKind<MultiError, 1> = FullKind::new_with_props(
+    "MULTI_ERROR",
+    Some("one or more errors occurred ({count})"),
+    &AGGREGATE_TAG,
+    ["count"],
+);
+
+// endregion:   --- MULTI_ERROR
+
+//===========================
+// region:      --- MultiError
+
+/// Child of a [`MultiError`], with an optional path identifying what the error refers to,
+/// e.g., `items[2].name` or the index of a batch invocation.
+#[derive(Debug)]
+pub struct ChildError {
+    pub path: Option<String>,
+    pub error: Error,
+}
+
+/// Ordered list of child errors. See [`MULTI_ERROR`].
+#[derive(Debug, Default)]
+pub struct MultiError {
+    children: Vec<ChildError>,
+}
+
+impl MultiError {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, error: Error) {
+        self.children.push(ChildError { path: None, error });
+    }
+
+    pub fn push_at(&mut self, path: impl Into<String>, error: Error) {
+        self.children.push(ChildError {
+            path: Some(path.into()),
+            error,
+        });
+    }
+
+    pub fn children(&self) -> &[ChildError] {
+        &self.children
+    }
+
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns a [`MULTI_ERROR`] error with `self` as its payload.
+    #[track_caller]
+    pub fn into_error(self) -> Error {
+        let count = self.children.len() as i64;
+        MULTI_ERROR.error_with_values_payload([count], self)
+    }
+
+    /// Returns `Ok(())` if `self` has no children and `Err(self.into_error())` otherwise.
+    #[track_caller]
+    pub fn into_result(self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.into_error())
+        }
+    }
+
+    /// Returns the serializable form of the children. Children that are themselves [`MULTI_ERROR`]s are
+    /// nested.
+    pub fn to_ser_children(&self) -> Vec<SerChildError> {
+        self.children
+            .iter()
+            .map(|child| SerChildError {
+                path: child.path.clone(),
+                error: child.error.to_sererror_no_payload_src([]),
+                errors: match child.error.downcast_payload_ref::<MultiError>() {
+                    Some(multi) => multi.to_ser_children(),
+                    None => Vec::new(),
+                },
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Error> for MultiError {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        let children = iter
+            .into_iter()
+            .map(|error| ChildError { path: None, error })
+            .collect();
+        Self { children }
+    }
+}
+
+impl Display for MultiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            if let Some(path) = &child.path {
+                write!(f, "{path}: ")?;
+            }
+            Display::fmt(&child.error, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for MultiError {}
+
+/// Serializes as an array of [`SerChildError`]s.
+impl Serialize for MultiError {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_ser_children().serialize(serializer)
+    }
+}
+
+// endregion:   --- MultiError
+
+//===========================
+// region:      --- SerChildError
+
+/// Serializable form of a [`ChildError`].
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct SerChildError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(flatten)]
+    pub error: SerError,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<SerChildError>,
+}
+
+impl SerChildError {
+    /// Returns `self` with its message and those of its nested children localized (see [`SerError::localized`]).
+    pub fn localized<CTX: ErrCtx>(self) -> Self {
+        Self {
+            path: self.path,
+            error: self.error.localized::<CTX>(),
+            errors: self
+                .errors
+                .into_iter()
+                .map(|child| child.localized::<CTX>())
+                .collect(),
+        }
+    }
+}
+
+// endregion:   --- SerChildError
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BasicKind, PropsKind, Tag, VALIDATION_TAG};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static FIELD_ERROR: PropsKind<1> = PropsKind::new_with_props(
+        "MULTI_FIELD_ERROR",
+        Some("bad value {value}"),
+        &FOO_TAG,
+        ["value"],
+    );
+
+    static CHECK_ERROR: BasicKind = BasicKind::new("MULTI_CHECK_ERROR", None, &VALIDATION_TAG);
+
+    #[test]
+    fn test_multi_error() {
+        assert!(MultiError::new().into_result().is_ok());
+
+        let single = MultiError::from_iter([CHECK_ERROR.error()]).into_error();
+        assert_eq!(single.to_string(), "one or more errors occurred (1)");
+
+        let mut inner = MultiError::new();
+        inner.push_at("items[0]", FIELD_ERROR.error_with_values([1]));
+        inner.push(CHECK_ERROR.error());
+
+        let mut multi = MultiError::new();
+        multi.push_at("name", FIELD_ERROR.error_with_values(["x"]));
+        multi.push_at("items", inner.into_error());
+        assert_eq!(
+            multi.to_string(),
+            "name: bad value x; items: one or more errors occurred (2)"
+        );
+
+        let err = multi.into_result().unwrap_err();
+        assert!(err.has_kind(MULTI_ERROR.kind_id()));
+        assert_eq!(err.props().prop_i64("count"), Some(2));
+
+        let ser_err = err
+            .downcast_payload::<MultiError>()
+            .unwrap()
+            .into_sererror_with_payload([]);
+        let json = serde_json::to_value(&ser_err).unwrap();
+        let children = json["payload"].as_array().unwrap();
+        assert_eq!(children[0]["path"], "name");
+        assert_eq!(children[0]["msg"], "bad value x");
+        assert!(children[0].get("errors").is_none());
+        assert_eq!(children[1]["kind_id"], "MULTI_ERROR");
+        let nested = children[1]["errors"].as_array().unwrap();
+        assert_eq!(nested[0]["path"], "items[0]");
+        assert_eq!(
+            nested[0]["props"]["pairs"][0],
+            serde_json::json!(["value", 1])
+        );
+        assert_eq!(nested[1]["kind_id"], "MULTI_CHECK_ERROR");
+        assert!(nested[1].get("path").is_none());
+    }
+}
@@ -1,13 +1,13 @@
-use crate::error::TRUNC;
-use crate::hash::hash_sha256_of_str_arr;
-use crate::string;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::error::Error as StdError;
 use std::fmt::{Debug, Display};
 use std::result;
 
-use super::Payload;
+use super::{redaction_policy, Payload, RedactionPolicy, REDACTION_DROPPED};
+use crate::string;
 
 //===========================
 // region:      --- NullError
@@ -33,6 +33,21 @@ pub trait KindTypeInfo {
     type Src: SendSyncStaticError;
 }
 
+/// Object-safe access to the static information of an error kind, e.g., for registration in the
+/// kind registry (see [`register_kinds`](super::register_kinds)).
+pub trait KindInfo: Send + Sync {
+    fn kind_id(&self) -> &KindId;
+
+    fn tag(&self) -> &'static Tag;
+
+    /// Message template of the kind.
+    fn msg(&self) -> &'static str;
+
+    fn prop_names(&self) -> &[&'static str];
+
+    fn has_ref_id(&self) -> bool;
+}
+
 pub trait SendSyncStaticError: StdError + Send + Sync + 'static {}
 
 impl<T> SendSyncStaticError for T where T: StdError + Send + Sync + 'static + ?Sized {}
@@ -42,8 +57,88 @@ impl<T> SendSyncStaticError for T where T: StdError + Send + Sync + 'static + ?S
 //===========================
 // region:      --- Tag
 
-#[derive(Debug, Serialize, PartialEq, Eq)]
-pub struct Tag(pub &'static str);
+/// Category of error kinds. A tag can have a parent tag, which makes tags form a hierarchy of families
+/// (see [`Self::is_a`]). Tags are compared by address, like [`KindId`]s, so tags should be statics and
+/// distinct statics are distinct tags even if they have the same name.
+pub struct Tag {
+    name: &'static str,
+    parent: Option<&'static Tag>,
+}
+
+impl Tag {
+    pub const fn new(name: &'static str) -> Self {
+        Self { name, parent: None }
+    }
+
+    /// Sets the parent of the tag. Fails the build if static tags' parents form a cycle.
+    ///
+    /// ```compile_fail
+    /// use foa::error::Tag;
+    ///
+    /// static FOO_TAG: Tag = Tag::new("FOO").with_parent(&BAR_TAG);
+    /// static BAR_TAG: Tag = Tag::new("BAR").with_parent(&FOO_TAG);
+    /// ```
+    pub const fn with_parent(self, parent: &'static Tag) -> Self {
+        // Walking up to the root requires the parents of static tags to be evaluated, which fails
+        // const evaluation with a cycle error if a tag is its own ancestor.
+        let mut ancestor = parent;
+        while let Some(next) = ancestor.parent {
+            ancestor = next;
+        }
+        Self {
+            name: self.name,
+            parent: Some(parent),
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn parent(&self) -> Option<&'static Tag> {
+        self.parent
+    }
+
+    /// Returns `self` followed by its parent, its parent's parent, and so on.
+    pub fn ancestors(&self) -> impl Iterator<Item = &Tag> {
+        std::iter::successors(Some(self), |tag| tag.parent)
+    }
+
+    /// Whether `self` is `other` or a descendant of `other`.
+    pub fn is_a(&self, other: &Tag) -> bool {
+        self.ancestors().any(|tag| tag == other)
+    }
+}
+
+impl Tag {
+    fn address(&self) -> usize {
+        self as *const Self as usize
+    }
+}
+
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl Eq for Tag {}
+
+impl Debug for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Tag").field(&self.name).finish()
+    }
+}
+
+/// Serializes as the tag's name.
+impl Serialize for Tag {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.name)
+    }
+}
 
 // endregion:   --- Tag
 
@@ -51,7 +146,7 @@ pub struct Tag(pub &'static str);
 // region:      --- BacktraceSpec
 
 /// Specifies different backtrace generation modes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BacktraceSpec {
     /// A backtrace is always generated
     Yes,
@@ -96,68 +191,303 @@ impl Eq for KindId {}
 
 // endregion:   --- KindId
 
+//===========================
+// region:      --- PropValue
+
+/// Value of a prop. Serializes as the corresponding native JSON type.
+///
+/// Integers are [`Self::Int`] if they fit in an `i64` and [`Self::UInt`] if they only fit in a `u64`, both
+/// when converted from Rust integers and when deserialized. 128-bit integers that fit in neither are
+/// converted to [`Self::Str`] with their decimal representation.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PropValue {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(String),
+    Json(serde_json::Value),
+}
+
+impl PropValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Str(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Int(v) => u64::try_from(*v).ok(),
+            Self::UInt(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_json(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::Json(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// Floats are compared with [`f64::total_cmp`], so that equality is reflexive.
+impl PartialEq for PropValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Bool(v1), Self::Bool(v2)) => v1 == v2,
+            (Self::Int(v1), Self::Int(v2)) => v1 == v2,
+            (Self::UInt(v1), Self::UInt(v2)) => v1 == v2,
+            (Self::Float(v1), Self::Float(v2)) => v1.total_cmp(v2) == Ordering::Equal,
+            (Self::Str(v1), Self::Str(v2)) => v1 == v2,
+            (Self::Json(v1), Self::Json(v2)) => v1 == v2,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for PropValue {}
+
+impl Debug for PropValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(v) => Debug::fmt(v, f),
+            Self::Int(v) => Debug::fmt(v, f),
+            Self::UInt(v) => Debug::fmt(v, f),
+            Self::Float(v) => Debug::fmt(v, f),
+            Self::Str(v) => Debug::fmt(v, f),
+            Self::Json(v) => Display::fmt(v, f),
+        }
+    }
+}
+
+/// Strings are displayed without quotes and JSON values as compact JSON.
+impl Display for PropValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(v) => Display::fmt(v, f),
+            Self::Int(v) => Display::fmt(v, f),
+            Self::UInt(v) => Display::fmt(v, f),
+            Self::Float(v) => Display::fmt(v, f),
+            Self::Str(v) => f.write_str(v),
+            Self::Json(v) => Display::fmt(v, f),
+        }
+    }
+}
+
+macro_rules! prop_value_from {
+    ($variant:ident, $($t:ty),+) => {
+        $(
+            impl From<$t> for PropValue {
+                fn from(value: $t) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )+
+    };
+}
+
+prop_value_from!(Bool, bool);
+prop_value_from!(Int, i8, i16, i32, i64, u8, u16, u32);
+prop_value_from!(Float, f32, f64);
+prop_value_from!(Str, &str, String, &String, Cow<'_, str>);
+prop_value_from!(Json, serde_json::Value);
+
+impl From<u64> for PropValue {
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(v) => Self::Int(v),
+            Err(_) => Self::UInt(value),
+        }
+    }
+}
+
+impl From<usize> for PropValue {
+    fn from(value: usize) -> Self {
+        (value as u64).into()
+    }
+}
+
+impl From<i128> for PropValue {
+    fn from(value: i128) -> Self {
+        if let Ok(v) = i64::try_from(value) {
+            Self::Int(v)
+        } else if let Ok(v) = u64::try_from(value) {
+            Self::UInt(v)
+        } else {
+            Self::Str(value.to_string())
+        }
+    }
+}
+
+impl From<u128> for PropValue {
+    fn from(value: u128) -> Self {
+        match u64::try_from(value) {
+            Ok(v) => v.into(),
+            Err(_) => Self::Str(value.to_string()),
+        }
+    }
+}
+
+// endregion:   --- PropValue
+
 //===========================
 // region:      --- Props
 
 #[derive(Deserialize, Clone, PartialEq, Eq)]
 pub struct Props {
-    pub(crate) pairs: Vec<(String, String)>,
+    pub(crate) pairs: Vec<(String, PropValue)>,
     pub(crate) protected: bool,
 }
 
 impl Props {
-    pub fn pairs(&self) -> impl Iterator<Item = (&str, &str)> {
-        self.pairs.iter().map(|p| (p.0.as_str(), p.1.as_str()))
+    pub fn pairs(&self) -> impl Iterator<Item = (&str, &PropValue)> {
+        self.pairs.iter().map(|p| (p.0.as_str(), &p.1))
     }
 
-    pub fn prop_value(&self, key: &str) -> Option<&str> {
-        self.pairs
-            .iter()
-            .find(|&p| p.0 == key)
-            .map(|p| p.1.as_str())
+    /// Returns the name-value pairs with values converted to strings as per [`PropValue`]'s `Display`
+    /// implementation, e.g., for message interpolation.
+    pub fn str_pairs(&self) -> impl Iterator<Item = (&str, String)> {
+        self.pairs.iter().map(|p| (p.0.as_str(), p.1.to_string()))
+    }
+
+    pub fn prop(&self, key: &str) -> Option<&PropValue> {
+        self.pairs.iter().find(|&p| p.0 == key).map(|p| &p.1)
+    }
+
+    /// Returns the value of prop `key` converted to a string as per [`PropValue`]'s `Display` implementation.
+    pub fn prop_value(&self, key: &str) -> Option<Cow<'_, str>> {
+        match self.prop(key)? {
+            PropValue::Str(v) => Some(Cow::Borrowed(v)),
+            v => Some(Cow::Owned(v.to_string())),
+        }
+    }
+
+    /// Returns the value of prop `key` if it is a string.
+    pub fn prop_str(&self, key: &str) -> Option<&str> {
+        self.prop(key)?.as_str()
+    }
+
+    /// Returns the value of prop `key` if it is an integer that fits in an `i64`.
+    pub fn prop_i64(&self, key: &str) -> Option<i64> {
+        self.prop(key)?.as_i64()
+    }
+
+    /// Returns the value of prop `key` if it is a non-negative integer that fits in a `u64`.
+    pub fn prop_u64(&self, key: &str) -> Option<u64> {
+        self.prop(key)?.as_u64()
+    }
+
+    /// Returns the value of prop `key` if it is a float.
+    pub fn prop_f64(&self, key: &str) -> Option<f64> {
+        self.prop(key)?.as_f64()
+    }
+
+    /// Returns the value of prop `key` if it is a bool.
+    pub fn prop_bool(&self, key: &str) -> Option<bool> {
+        self.prop(key)?.as_bool()
     }
 
-    /// Hashes value of fields whose names starts with '!'.
-    fn hashed_pairs(&self) -> (Vec<(String, String)>, bool) {
+    /// Returns the value of prop `key` if it is nested JSON.
+    pub fn prop_json(&self, key: &str) -> Option<&serde_json::Value> {
+        self.prop(key)?.as_json()
+    }
+
+    /// Returns a copy of `self` with the values of props whose names start with '!' redacted as per `policy`.
+    /// Props that are already protected are returned unchanged.
+    pub fn redacted(&self, policy: &RedactionPolicy) -> Self {
+        if self.protected || *policy == RedactionPolicy::Reveal {
+            return self.clone();
+        }
         let mut protected = false;
         let pairs = self
             .pairs
             .iter()
-            .map(|(name, value)| {
+            .filter_map(|(name, value)| {
                 let value = if name.starts_with("!") {
                     protected = true;
-                    let vhash = hash_sha256_of_str_arr(&[value]);
-                    string::base64_encode_trunc_of_u8_arr(&vhash, TRUNC)
+                    PropValue::Str(policy.redact(&value.to_string())?)
                 } else {
                     value.to_owned()
                 };
-                (name.to_owned(), value)
+                Some((name.to_owned(), value))
             })
             .collect::<Vec<_>>();
-        (pairs, protected)
+        Self { pairs, protected }
     }
 
-    pub fn safe_props(&self) -> Self {
-        if cfg!(debug_assertions) || self.protected {
-            self.clone()
+    /// Adds a prop. The prop is protected if its name starts with `'!'`, in which case its value is redacted
+    /// as per `policy` if `self` is already protected, because protected props are not redacted again.
+    pub(crate) fn push(&mut self, name: &str, value: PropValue, policy: &RedactionPolicy) {
+        let value = if self.protected && name.starts_with('!') {
+            match policy.redact(&value.to_string()) {
+                Some(value) => PropValue::Str(value),
+                None => return,
+            }
         } else {
-            let (pairs, protected) = self.hashed_pairs();
-            Self { pairs, protected }
+            value
+        };
+        self.pairs.push((name.to_owned(), value));
+    }
+
+    /// Returns a copy of `self` redacted as per the current [`redaction_policy`].
+    pub fn safe_props(&self) -> Self {
+        self.redacted(&redaction_policy())
+    }
+
+    /// Returns `raw_msg` interpolated with `self` redacted as per `policy`. The placeholders of protected props
+    /// omitted under [`RedactionPolicy::Drop`] are replaced by [`REDACTION_DROPPED`] rather than left as is.
+    pub fn interpolated(&self, raw_msg: &str, policy: &RedactionPolicy) -> String {
+        let props = self.redacted(policy);
+        let msg = string::interpolated_props(raw_msg, props.str_pairs());
+        if !props.protected {
+            return msg;
         }
+        let dropped = string::placeholder_names(&msg)
+            .into_iter()
+            .filter(|name| name.starts_with('!'))
+            .map(|name| (name.to_owned(), REDACTION_DROPPED))
+            .collect::<Vec<_>>();
+        string::interpolated_props(&msg, dropped.into_iter())
+    }
+
+    /// Returns `raw_msg` interpolated with `self` redacted as per the current [`redaction_policy`].
+    pub fn safe_interpolated(&self, raw_msg: &str) -> String {
+        self.interpolated(raw_msg, &redaction_policy())
     }
 }
 
 impl Debug for Props {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let pairs = if cfg!(debug_assertions) || self.protected {
-            &self.pairs
-        } else {
-            &self.hashed_pairs().0
-        };
+        let safe = self.safe_props();
         f.write_str("Props { pairs: ")?;
-        pairs.fmt(f)?;
+        safe.pairs.fmt(f)?;
         f.write_str(", protected: ")?;
-        Debug::fmt(&self.protected, f)?;
+        Debug::fmt(&safe.protected, f)?;
         f.write_str(" }")
     }
 }
@@ -167,15 +497,10 @@ impl Serialize for Props {
     where
         S: serde::Serializer,
     {
+        let safe = self.safe_props();
         let mut state = serializer.serialize_struct("Props", 2)?;
-        if cfg!(debug_assertions) || self.protected {
-            state.serialize_field("pairs", &self.pairs)?;
-            state.serialize_field("protected", &self.protected)?;
-        } else {
-            let (pairs, protected) = self.hashed_pairs();
-            state.serialize_field("pairs", &pairs)?;
-            state.serialize_field("protected", &protected)?;
-        };
+        state.serialize_field("pairs", &safe.pairs)?;
+        state.serialize_field("protected", &safe.protected)?;
         state.end()
     }
 }
@@ -0,0 +1,234 @@
+use super::{PropsKind, Result, INTERNAL_TAG, TRUNC};
+use crate::{hash::hmac_sha256_of_str_arr, string};
+use arc_swap::ArcSwap;
+use std::{
+    env,
+    fmt::Debug,
+    sync::{Arc, OnceLock},
+};
+
+//===========================
+// region:      --- Error kinds
+
+pub static REDACTION_CONFIG_ERROR: PropsKind<1> = PropsKind::new_with_props(
+    "REDACTION_CONFIG_ERROR",
+    Some("invalid redaction policy configuration: {reason}"),
+    &INTERNAL_TAG,
+    ["reason"],
+);
+
+// endregion:   --- Error kinds
+
+//===========================
+// region:      --- RedactionPolicy
+
+/// Name of the environment variable that selects the redaction policy (see [`RedactionPolicy::from_env`]).
+pub const REDACTION_POLICY_VAR: &str = "FOA_REDACTION_POLICY";
+
+/// Name of the environment variable that holds the secret key for [`RedactionPolicy::Hmac`].
+pub const REDACTION_SECRET_VAR: &str = "FOA_REDACTION_SECRET";
+
+/// Replacement value for protected props under [`RedactionPolicy::Mask`].
+pub const REDACTION_MASK: &str = "********";
+
+/// Replacement for the placeholders of protected props omitted under [`RedactionPolicy::Drop`] when messages
+/// are interpolated (see [`Props::interpolated`](super::Props::interpolated)).
+pub const REDACTION_DROPPED: &str = "[redacted]";
+
+/// Determines how the values of protected props (those whose names start with `'!'`) are rendered by
+/// [`Props`](super::Props)'s `Debug` and `Serialize` implementations and by
+/// [`Props::safe_props`](super::Props::safe_props).
+#[derive(Clone, PartialEq, Eq)]
+pub enum RedactionPolicy {
+    /// Values are shown as is.
+    Reveal,
+    /// Values are replaced by their HMAC-SHA256 with the given secret key, Base64-encoded and truncated to
+    /// [`TRUNC`] bytes. Equal values produce equal digests, so they can be correlated across errors and logs.
+    Hmac(Vec<u8>),
+    /// Values are replaced by [`REDACTION_MASK`].
+    Mask,
+    /// Protected props are omitted.
+    Drop,
+}
+
+impl RedactionPolicy {
+    /// Creates a policy from a configuration value, one of `reveal`, `hmac`, `mask`, or `drop`.
+    /// `secret` is required for, and only used by, `hmac`.
+    pub fn from_config(policy: &str, secret: Option<&[u8]>) -> Result<Self> {
+        match (policy.trim().to_ascii_lowercase().as_str(), secret) {
+            ("reveal", _) => Ok(Self::Reveal),
+            ("mask", _) => Ok(Self::Mask),
+            ("drop", _) => Ok(Self::Drop),
+            ("hmac", Some(secret)) if !secret.is_empty() => Ok(Self::Hmac(secret.to_vec())),
+            ("hmac", _) => Err(REDACTION_CONFIG_ERROR.error_with_values(["missing hmac secret"])),
+            _ => Err(REDACTION_CONFIG_ERROR.error_with_values(["unknown policy"])),
+        }
+    }
+
+    /// Creates a policy from the environment variables [`REDACTION_POLICY_VAR`] and [`REDACTION_SECRET_VAR`]
+    /// (see [`Self::from_config`]). Returns the default policy if [`REDACTION_POLICY_VAR`] is not set.
+    pub fn from_env() -> Result<Self> {
+        match env::var(REDACTION_POLICY_VAR) {
+            Err(_) => Ok(Self::default()),
+            Ok(policy) => {
+                let secret = env::var(REDACTION_SECRET_VAR).ok();
+                Self::from_config(&policy, secret.as_deref().map(str::as_bytes))
+            }
+        }
+    }
+
+    /// Returns the redacted value of a protected prop, or `None` if the prop is to be omitted.
+    pub fn redact(&self, value: &str) -> Option<String> {
+        match self {
+            Self::Reveal => Some(value.to_owned()),
+            Self::Hmac(key) => {
+                let digest = hmac_sha256_of_str_arr(key, &[value]);
+                Some(string::base64_encode_trunc_of_u8_arr(&digest, TRUNC))
+            }
+            Self::Mask => Some(REDACTION_MASK.to_owned()),
+            Self::Drop => None,
+        }
+    }
+}
+
+/// [`RedactionPolicy::Reveal`] in debug builds and [`RedactionPolicy::Mask`] otherwise.
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            Self::Reveal
+        } else {
+            Self::Mask
+        }
+    }
+}
+
+/// Does not show the secret key.
+impl Debug for RedactionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reveal => f.write_str("Reveal"),
+            Self::Hmac(_) => f.write_str("Hmac(..)"),
+            Self::Mask => f.write_str("Mask"),
+            Self::Drop => f.write_str("Drop"),
+        }
+    }
+}
+
+fn policy_static() -> &'static ArcSwap<RedactionPolicy> {
+    static POLICY: OnceLock<ArcSwap<RedactionPolicy>> = OnceLock::new();
+    POLICY.get_or_init(|| ArcSwap::from_pointee(RedactionPolicy::default()))
+}
+
+/// Returns the current redaction policy, [`RedactionPolicy::default`] unless set with [`set_redaction_policy`].
+pub fn redaction_policy() -> Arc<RedactionPolicy> {
+    policy_static().load_full()
+}
+
+/// Sets the redaction policy used from now on. Typically called at application startup, e.g., with
+/// [`RedactionPolicy::from_env`].
+pub fn set_redaction_policy(policy: RedactionPolicy) {
+    policy_static().store(Arc::new(policy));
+}
+
+// endregion:   --- RedactionPolicy
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::Props;
+
+    fn props() -> Props {
+        Props {
+            pairs: vec![
+                ("abc".into(), "hi there".into()),
+                ("!email".into(), "bar@example.com".into()),
+            ],
+            protected: false,
+        }
+    }
+
+    #[test]
+    fn test_redacted() {
+        let key_a = RedactionPolicy::Hmac(b"secret-a".to_vec());
+        let key_b = RedactionPolicy::Hmac(b"secret-b".to_vec());
+
+        let revealed = props().redacted(&RedactionPolicy::Reveal);
+        assert_eq!(revealed, props());
+
+        let hashed_a = props().redacted(&key_a);
+        let hashed_b = props().redacted(&key_b);
+        assert!(hashed_a.protected);
+        assert_eq!(hashed_a.prop_str("abc"), Some("hi there"));
+        let digest_a = hashed_a.prop_str("!email").unwrap();
+        assert_eq!(digest_a.len(), 12);
+        assert_ne!(digest_a, "bar@example.com");
+        assert_eq!(Some(digest_a), props().redacted(&key_a).prop_str("!email"));
+        assert_ne!(hashed_b.prop_str("!email"), Some(digest_a));
+
+        let masked = props().redacted(&RedactionPolicy::Mask);
+        assert_eq!(masked.prop_str("!email"), Some(REDACTION_MASK));
+
+        let dropped = props().redacted(&RedactionPolicy::Drop);
+        assert!(dropped.protected);
+        assert_eq!(
+            dropped.str_pairs().collect::<Vec<_>>(),
+            [("abc", "hi there".to_owned())]
+        );
+
+        let raw_msg = "foo: {abc}, {!email}";
+        assert_eq!(
+            props().interpolated(raw_msg, &RedactionPolicy::Reveal),
+            "foo: hi there, bar@example.com"
+        );
+        assert_eq!(
+            props().interpolated(raw_msg, &RedactionPolicy::Mask),
+            format!("foo: hi there, {REDACTION_MASK}")
+        );
+        assert_eq!(
+            props().interpolated(raw_msg, &RedactionPolicy::Drop),
+            format!("foo: hi there, {REDACTION_DROPPED}")
+        );
+        assert_eq!(
+            dropped.interpolated(raw_msg, &RedactionPolicy::Drop),
+            format!("foo: hi there, {REDACTION_DROPPED}")
+        );
+
+        // Already protected props are not redacted again.
+        assert_eq!(masked.redacted(&key_a), masked);
+    }
+
+    #[test]
+    fn test_push_protected() {
+        let mut props = props();
+        props.push("!secret", "s3cr3t".into(), &RedactionPolicy::Mask);
+        assert_eq!(props.prop_str("!secret"), Some("s3cr3t"));
+
+        let mut masked = props.redacted(&RedactionPolicy::Mask);
+        masked.push("!token", "t0k3n".into(), &RedactionPolicy::Mask);
+        masked.push("def", "plain".into(), &RedactionPolicy::Mask);
+        assert_eq!(masked.prop_str("!token"), Some(REDACTION_MASK));
+        assert_eq!(masked.prop_str("def"), Some("plain"));
+
+        masked.push("!dropped", "d".into(), &RedactionPolicy::Drop);
+        assert_eq!(masked.prop("!dropped"), None);
+    }
+
+    #[test]
+    fn test_from_config() {
+        assert_eq!(
+            RedactionPolicy::from_config("HMAC", Some(b"k")).unwrap(),
+            RedactionPolicy::Hmac(b"k".to_vec())
+        );
+        assert_eq!(
+            RedactionPolicy::from_config("drop", None).unwrap(),
+            RedactionPolicy::Drop
+        );
+        let err = RedactionPolicy::from_config("hmac", None).unwrap_err();
+        assert!(err.has_kind(REDACTION_CONFIG_ERROR.kind_id()));
+        assert!(RedactionPolicy::from_config("hash", None).is_err());
+        assert_eq!(
+            format!("{:?}", RedactionPolicy::Hmac(b"k".to_vec())),
+            "Hmac(..)"
+        );
+    }
+}
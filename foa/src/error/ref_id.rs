@@ -0,0 +1,115 @@
+use super::ref_id_u32_hex_lower;
+use crate::string::hex_lower_of_u8_arr;
+use arc_swap::ArcSwap;
+use rand::random;
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+//===========================
+// region:      --- RefIdGen
+
+/// Generator of the reference ids of errors whose kinds have ref ids enabled
+/// (see [`FullKind::with_ref_id`](super::FullKind::with_ref_id)).
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RefIdGen {
+    /// Random 32-bit number in lower hex format, e.g., `9f86d081`.
+    #[default]
+    RandomU32,
+    /// 128-bit number in lower hex format whose first 48 bits are the number of milliseconds since the Unix
+    /// epoch and whose remaining 80 bits are random, so that ids sort by creation time.
+    TimeOrdered,
+    /// Per-process sequence number, prefixed by a random 32-bit process id in lower hex format,
+    /// e.g., `9f86d081-42`.
+    Sequential,
+    /// Id returned by the given function, e.g., the current request or trace id taken from a task-local
+    /// (see [`ref_id_from_task_local`](crate::tokio::task_local_ext::ref_id_from_task_local)), falling back
+    /// to [`Self::RandomU32`] if the function returns `None`.
+    Custom(fn() -> Option<String>),
+}
+
+impl RefIdGen {
+    pub fn generate(&self) -> String {
+        match self {
+            Self::RandomU32 => ref_id_u32_hex_lower(),
+            Self::TimeOrdered => time_ordered_ref_id(),
+            Self::Sequential => sequential_ref_id(),
+            Self::Custom(f) => f().unwrap_or_else(ref_id_u32_hex_lower),
+        }
+    }
+}
+
+fn time_ordered_ref_id() -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let mut bytes = [0_u8; 16];
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    bytes[6..].copy_from_slice(&random::<[u8; 10]>());
+    hex_lower_of_u8_arr(&bytes)
+}
+
+fn sequential_ref_id() -> String {
+    static PROCESS_ID: OnceLock<String> = OnceLock::new();
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    let process_id = PROCESS_ID.get_or_init(ref_id_u32_hex_lower);
+    let seq = SEQ.fetch_add(1, Ordering::Relaxed);
+    format!("{process_id}-{seq}")
+}
+
+fn gen_static() -> &'static ArcSwap<RefIdGen> {
+    static GEN: OnceLock<ArcSwap<RefIdGen>> = OnceLock::new();
+    GEN.get_or_init(|| ArcSwap::from_pointee(RefIdGen::default()))
+}
+
+/// Returns the current ref id generator, [`RefIdGen::default`] unless set with [`set_ref_id_gen`].
+pub fn ref_id_gen() -> RefIdGen {
+    **gen_static().load()
+}
+
+/// Sets the ref id generator used from now on. Typically called at application startup.
+pub fn set_ref_id_gen(ref_id_gen: RefIdGen) {
+    gen_static().store(Arc::new(ref_id_gen));
+}
+
+/// Returns a new ref id from the current generator.
+pub fn new_ref_id() -> String {
+    ref_id_gen().generate()
+}
+
+// endregion:   --- RefIdGen
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generators() {
+        let id = RefIdGen::RandomU32.generate();
+        assert_eq!(id.len(), 8);
+
+        let id1 = RefIdGen::TimeOrdered.generate();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let id2 = RefIdGen::TimeOrdered.generate();
+        assert_eq!(id1.len(), 32);
+        assert!(id1 < id2);
+
+        let id1 = RefIdGen::Sequential.generate();
+        let id2 = RefIdGen::Sequential.generate();
+        let (prefix1, seq1) = id1.split_once('-').unwrap();
+        let (prefix2, seq2) = id2.split_once('-').unwrap();
+        assert_eq!(prefix1, prefix2);
+        assert!(seq1.parse::<u64>().unwrap() < seq2.parse::<u64>().unwrap());
+
+        assert_eq!(
+            RefIdGen::Custom(|| Some("req-1".into())).generate(),
+            "req-1"
+        );
+        assert_eq!(RefIdGen::Custom(|| None).generate().len(), 8);
+    }
+}
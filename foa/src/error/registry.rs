@@ -0,0 +1,210 @@
+use super::{
+    serde::{JSON_DESER_ERROR, UNKNOWN_REMOTE_ERROR},
+    KindId, KindInfo, PropsKind, Result, Tag, AGGREGATE_TAG, AUTH_TAG, BACKTRACE_CONFIG_ERROR,
+    CLIENT_TAG, CONFLICT_TAG, FORBIDDEN_TAG, INTERNAL_TAG, LIB_DEPENDENCY_TAG, MULTI_ERROR,
+    NOT_FOUND_TAG, RATE_LIMITED_TAG, REDACTION_CONFIG_ERROR, REMOTE_TAG, RUNTIME_TAG,
+    TRANSIENT_TAG, UNEXPECTED_ERROR, UNEXPECTED_TAG, VALIDATION_TAG,
+};
+use crate::{
+    context::{MSG_CATALOG_IO_ERROR, MSG_CATALOG_MISMATCH_ERROR, MSG_CATALOG_PARSE_ERROR},
+    db::sqlx::DB_ERROR,
+    tokio::task_local::TASK_LOCAL_ERROR,
+    validation::validc::VALIDATION_ERROR,
+};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    sync::{OnceLock, RwLock},
+};
+
+//===========================
+// region:      --- Registry
+
+pub static DUPLICATE_KIND_ERROR: PropsKind<1> = PropsKind::new_with_props(
+    "DUPLICATE_KIND_ERROR",
+    Some("a different error kind named {kind_id} is already registered"),
+    &INTERNAL_TAG,
+    ["kind_id"],
+);
+
+type Registry = RwLock<BTreeMap<&'static str, &'static dyn KindInfo>>;
+
+/// Returns the registry, initialized with the kinds defined in this crate.
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let foa_kinds: [&'static dyn KindInfo; 13] = [
+            &UNEXPECTED_ERROR,
+            &JSON_DESER_ERROR,
+            &UNKNOWN_REMOTE_ERROR,
+            &DUPLICATE_KIND_ERROR,
+            &MULTI_ERROR,
+            &REDACTION_CONFIG_ERROR,
+            &BACKTRACE_CONFIG_ERROR,
+            &DB_ERROR,
+            &TASK_LOCAL_ERROR,
+            &VALIDATION_ERROR,
+            &MSG_CATALOG_IO_ERROR,
+            &MSG_CATALOG_PARSE_ERROR,
+            &MSG_CATALOG_MISMATCH_ERROR,
+        ];
+        let map = foa_kinds
+            .into_iter()
+            .map(|kind| (kind.kind_id().0, kind))
+            .collect();
+        RwLock::new(map)
+    })
+}
+
+/// Registers error kinds so they can be listed (see [`registered_kinds`] and [`kind_catalog`]) and looked up by
+/// name (see [`registered_kind`]). Should be called at application startup.
+///
+/// Registering the same kind more than once has no effect. Returns a [`DUPLICATE_KIND_ERROR`] if a different
+/// kind with the same name has already been registered, in which case no kinds from `kinds` are registered.
+pub fn register_kinds(kinds: &[&'static dyn KindInfo]) -> Result<()> {
+    let mut map = registry().write().expect("kind registry lock poisoned");
+    let mut new_kinds = BTreeMap::<&'static str, &'static dyn KindInfo>::new();
+    for &kind in kinds {
+        let name = kind.kind_id().0;
+        let existing = map.get(name).or_else(|| new_kinds.get(name));
+        match existing {
+            Some(existing) if existing.kind_id() != kind.kind_id() => {
+                return Err(DUPLICATE_KIND_ERROR.error_with_values([name]));
+            }
+            Some(_) => {}
+            None => {
+                new_kinds.insert(name, kind);
+            }
+        }
+    }
+    map.extend(new_kinds);
+    Ok(())
+}
+
+/// Returns the registered kind with the given name, if any.
+pub fn registered_kind(name: &str) -> Option<&'static dyn KindInfo> {
+    let map = registry().read().expect("kind registry lock poisoned");
+    map.get(name).copied()
+}
+
+/// Returns the tag with the given name among the tags of the registered kinds and the tags defined in this
+/// crate, if any.
+pub fn registered_tag(name: &str) -> Option<&'static Tag> {
+    let foa_tags = [
+        &INTERNAL_TAG,
+        &RUNTIME_TAG,
+        &VALIDATION_TAG,
+        &UNEXPECTED_TAG,
+        &LIB_DEPENDENCY_TAG,
+        &REMOTE_TAG,
+        &AGGREGATE_TAG,
+        &CLIENT_TAG,
+        &NOT_FOUND_TAG,
+        &CONFLICT_TAG,
+        &AUTH_TAG,
+        &FORBIDDEN_TAG,
+        &RATE_LIMITED_TAG,
+        &TRANSIENT_TAG,
+    ];
+    foa_tags
+        .into_iter()
+        .chain(registered_kinds().into_iter().map(|kind| kind.tag()))
+        .find(|tag| tag.name() == name)
+}
+
+/// Returns the registered kinds, sorted by name.
+pub fn registered_kinds() -> Vec<&'static dyn KindInfo> {
+    let map = registry().read().expect("kind registry lock poisoned");
+    map.values().copied().collect()
+}
+
+// endregion:   --- Registry
+
+//===========================
+// region:      --- Catalog
+
+/// Catalog entry with the static information of a registered kind.
+#[derive(Debug, Serialize)]
+pub struct KindCatalogEntry {
+    pub kind_id: &'static KindId,
+    pub tag: &'static Tag,
+    pub msg: &'static str,
+    pub prop_names: &'static [&'static str],
+    pub has_ref_id: bool,
+}
+
+impl From<&'static dyn KindInfo> for KindCatalogEntry {
+    fn from(kind: &'static dyn KindInfo) -> Self {
+        Self {
+            kind_id: kind.kind_id(),
+            tag: kind.tag(),
+            msg: kind.msg(),
+            prop_names: kind.prop_names(),
+            has_ref_id: kind.has_ref_id(),
+        }
+    }
+}
+
+/// Returns the catalog of registered kinds, sorted by kind id.
+pub fn kind_catalog() -> Vec<KindCatalogEntry> {
+    registered_kinds().into_iter().map(Into::into).collect()
+}
+
+/// Returns the catalog of registered kinds as a JSON array (see [`kind_catalog`]).
+pub fn kind_catalog_json() -> String {
+    serde_json::to_string_pretty(&kind_catalog()).expect("kind catalog serialization error")
+}
+
+// endregion:   --- Catalog
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BasicKind, DeserKindId, FullKind, TransmuterKind};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static REG_FOO_ERROR: FullKind<(), 1> =
+        PropsKind::new_with_props("REG_FOO_ERROR", Some("foo {xyz}"), &FOO_TAG, ["xyz"])
+            .with_ref_id();
+
+    static REG_BAR_ERROR: TransmuterKind = TransmuterKind::new("REG_BAR_ERROR", None, &FOO_TAG);
+
+    static REG_FOO_ERROR_DUP: BasicKind = BasicKind::new("REG_FOO_ERROR", None, &FOO_TAG);
+
+    #[test]
+    fn test_registry() {
+        register_kinds(&[&REG_FOO_ERROR, &REG_BAR_ERROR]).unwrap();
+        register_kinds(&[&REG_FOO_ERROR]).unwrap();
+
+        let err = register_kinds(&[&REG_FOO_ERROR_DUP]).unwrap_err();
+        assert!(err.has_kind(DUPLICATE_KIND_ERROR.kind_id()));
+
+        let kind = registered_kind("REG_FOO_ERROR").unwrap();
+        assert_eq!(kind.kind_id(), REG_FOO_ERROR.kind_id());
+        assert!(registered_kind("VALIDATION_ERROR").is_some());
+        assert!(registered_kind("REG_BAZ_ERROR").is_none());
+
+        let deser_kind_id = DeserKindId("REG_BAR_ERROR".into());
+        assert_eq!(deser_kind_id.resolve(), Some(REG_BAR_ERROR.kind_id()));
+
+        let json = kind_catalog_json();
+        let catalog: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let entry = catalog
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["kind_id"] == "REG_FOO_ERROR")
+            .unwrap();
+        assert_eq!(
+            entry,
+            &serde_json::json!({
+                "kind_id": "REG_FOO_ERROR",
+                "tag": "FOO",
+                "msg": "foo {xyz}",
+                "prop_names": ["xyz"],
+                "has_ref_id": true,
+            })
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use super::{
+    rfc3339_utc, ser_source_chain, BoxPayload, Error, FrameFilter, Payload, SendSyncStaticError,
+    SharedError, StdBoxError, DEFAULT_FRAME_FILTER,
+};
+use std::{
+    any::Any,
+    backtrace::BacktraceStatus,
+    fmt::{Debug, Display},
+    io::{stderr, IsTerminal},
+    panic,
+};
+
+//===========================
+// region:      --- Report
+
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD: &str = "\x1b[1m";
+const CYAN: &str = "\x1b[36m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Width of the labels of the sections of a [`Report`], including the trailing `':'`.
+const LABEL_WIDTH: usize = 12;
+
+/// Multi-line, human-readable report of an [`Error`], for terminal output during development.
+/// Created with [`Error::report`] and rendered with `Display`. It is also the alternate `Debug` format
+/// (`{:#?}`) of [`Error`].
+///
+/// For example:
+/// ```text
+/// Error
+///   kind:       OUTER_ERROR
+///   tag:        FOO
+///   message:    outer message
+///   props:      abc: x
+///               !email: ***
+///   location:   src/foo.rs:12:9
+///   created_at: 2024-05-17T09:41:07.042Z
+///
+/// Caused by:
+///    0: inner message [INNER_ERROR]
+///    1: connection refused
+///
+/// Backtrace:
+///    0: app::foo at ./src/foo.rs:12
+///    1: tokio::runtime::task::poll (+5 collapsed)
+/// ```
+///
+/// The backtrace section is omitted if no backtrace was captured. Props are rendered as per
+/// [`Props::safe_props`](super::Props::safe_props).
+pub struct Report<'a, PLD = BoxPayload, SRC = StdBoxError> {
+    error: &'a Error<PLD, SRC>,
+    color: bool,
+    frame_filter: &'a FrameFilter<'a>,
+}
+
+impl<'a, PLD: Payload, SRC: SendSyncStaticError> Report<'a, PLD, SRC> {
+    /// Returns `self` with ANSI colors enabled or disabled. Colors are disabled by default.
+    pub fn with_color(self, color: bool) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Returns `self` with the backtrace filtered by `frame_filter` instead of [`DEFAULT_FRAME_FILTER`].
+    pub fn with_frame_filter(self, frame_filter: &'a FrameFilter<'a>) -> Self {
+        Self {
+            frame_filter,
+            ..self
+        }
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{style}{text}{RESET}")
+        } else {
+            text.to_owned()
+        }
+    }
+
+    fn write_section(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        label: &str,
+        lines: &[String],
+    ) -> std::fmt::Result {
+        let label = format!("{label}:");
+        for (i, line) in lines.iter().enumerate() {
+            let label = if i == 0 { label.as_str() } else { "" };
+            let label = self.paint(CYAN, &format!("{label:<LABEL_WIDTH$}"));
+            writeln!(f, "  {label}{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> Display for Report<'_, PLD, SRC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let err = self.error;
+        writeln!(f, "{}", self.paint(BOLD_RED, "Error"))?;
+        self.write_section(f, "kind", &[self.paint(BOLD, err.kind_id().0)])?;
+        self.write_section(f, "tag", &[err.tag().name().to_owned()])?;
+        self.write_section(f, "message", &[err.interpolated_msg()])?;
+
+        let props = err
+            .props()
+            .safe_props()
+            .str_pairs()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect::<Vec<_>>();
+        if !props.is_empty() {
+            self.write_section(f, "props", &props)?;
+        }
+        if has_payload(err.payload()) {
+            self.write_section(f, "payload", &[format!("{:?}", err.payload())])?;
+        }
+        if let Some(ref_id) = err.ref_id() {
+            self.write_section(f, "ref_id", &[ref_id.to_owned()])?;
+        }
+        self.write_section(f, "location", &[err.location().to_string()])?;
+        self.write_section(f, "created_at", &[rfc3339_utc(err.created_at())])?;
+        if !err.trail().is_empty() {
+            self.write_section(f, "trail", &[err.trail().to_string()])?;
+        }
+
+        let chain = ser_source_chain(err);
+        if !chain.is_empty() {
+            write!(f, "\n{}", self.paint(BOLD, "Caused by:"))?;
+            for (i, source) in chain.iter().enumerate() {
+                write!(f, "\n{i:>4}: {}", source.message)?;
+                if let Some(kind_id) = source.kind_id {
+                    write!(f, " [{}]", kind_id.0)?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        let backtrace = err.backtrace();
+        if backtrace.status() == BacktraceStatus::Captured {
+            write!(f, "\n{}", self.paint(BOLD, "Backtrace:"))?;
+            for line in self.frame_filter.render(backtrace).lines() {
+                write!(f, "\n{}", self.paint(DIM, line))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `false` if `payload` is `()`, boxed or not.
+fn has_payload<PLD: Payload>(payload: &PLD) -> bool {
+    let payload: &dyn Any = payload;
+    match payload.downcast_ref::<BoxPayload>() {
+        Some(payload) => !payload.is::<()>(),
+        None => !payload.is::<()>() && !payload.is::<Box<()>>(),
+    }
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
+    /// Returns the [`Report`] of `self`.
+    pub fn report(&self) -> Report<'_, PLD, SRC> {
+        Report {
+            error: self,
+            color: false,
+            frame_filter: &DEFAULT_FRAME_FILTER,
+        }
+    }
+}
+
+// endregion:   --- Report
+
+//===========================
+// region:      --- Handlers
+
+/// Error type for `main` functions, which is displayed as the [`Report`] of the error when returned from
+/// `main`, with colors if stderr is a terminal. [`Error`]s are converted into it by the `?` operator.
+pub struct MainError(pub Error);
+
+impl From<Error> for MainError {
+    fn from(value: Error) -> Self {
+        Self(value)
+    }
+}
+
+/// Used by the standard library to print the error returned from `main`.
+impl Debug for MainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0.report().with_color(stderr().is_terminal()), f)
+    }
+}
+
+/// Installs a panic hook that prints to stderr the [`Report`] of panics whose payload is an [`Error`] or a
+/// [`SharedError`], e.g., raised with [`std::panic::panic_any`], with colors if stderr is a terminal.
+/// Other panics are handled by the previously installed hook.
+pub fn set_report_panic_hook() {
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| match panic_error(info.payload()) {
+        Some(err) => {
+            let location = match info.location() {
+                Some(location) => format!(" at {location}"),
+                None => String::new(),
+            };
+            let report = err.report().with_color(stderr().is_terminal());
+            eprintln!("panicked{location}:\n{report}");
+        }
+        None => prev_hook(info),
+    }));
+}
+
+/// Returns the [`Error`] in a panic payload, if any, as per [`set_report_panic_hook`].
+fn panic_error(payload: &(dyn Any + Send)) -> Option<&Error> {
+    payload
+        .downcast_ref::<Error>()
+        .or_else(|| payload.downcast_ref::<SharedError>().map(|err| &**err))
+}
+
+// endregion:   --- Handlers
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BacktraceSpec, BasicKind, PayloadKind, PropsKind, Tag, TrivialError};
+    use std::panic::AssertUnwindSafe;
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static INNER_ERROR: PropsKind<1, TrivialError> = PropsKind::new_with_props(
+        "REPORT_INNER_ERROR",
+        Some("inner message: {abc}"),
+        &FOO_TAG,
+        ["abc"],
+    );
+
+    static OUTER_ERROR: BasicKind<StdBoxError> =
+        BasicKind::new("REPORT_OUTER_ERROR", Some("outer message"), &FOO_TAG)
+            .with_backtrace(BacktraceSpec::No);
+
+    static BT_ERROR: BasicKind =
+        BasicKind::new("REPORT_BT_ERROR", None, &FOO_TAG).with_backtrace(BacktraceSpec::Yes);
+
+    /// Payload whose `Debug` output is the same as that of `()`.
+    struct UnitLike;
+
+    impl Debug for UnitLike {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("()")
+        }
+    }
+
+    static PLD_ERROR: PayloadKind<UnitLike> =
+        PayloadKind::new_with_payload("REPORT_PLD_ERROR", Some("pld message"), &FOO_TAG)
+            .with_backtrace(BacktraceSpec::No);
+
+    #[test]
+    fn test_report() {
+        let inner = INNER_ERROR.error_with_values_src(["x"], TrivialError("dummy"));
+        let err = OUTER_ERROR.error_with_src(StdBoxError::new(inner));
+        let report = err.report().to_string();
+        let expected = format!(
+            "Error
+  kind:       REPORT_OUTER_ERROR
+  tag:        FOO
+  message:    outer message
+  location:   {}
+  created_at: {}
+
+Caused by:
+   0: inner message: x [REPORT_INNER_ERROR]
+   1: dummy
+",
+            err.location(),
+            rfc3339_utc(err.created_at())
+        );
+        assert_eq!(report, expected);
+        assert_eq!(format!("{err:#?}"), report);
+        assert!(format!("{err:?}").starts_with("Error { kind_id: KindId(REPORT_OUTER_ERROR"));
+
+        let inner = INNER_ERROR
+            .error_with_values_src(["x"], TrivialError("dummy"))
+            .with_prop("!email", "a@b.c");
+        let report = inner.report().with_color(true).to_string();
+        assert!(report.contains(&format!("{CYAN}props:      {RESET}abc: x\n")));
+        assert!(report.contains(&format!("{CYAN}            {RESET}!email: ")));
+
+        let err = BT_ERROR.error();
+        let report = format!("{:?}", MainError::from(err));
+        assert!(report.contains("\nBacktrace:\n"));
+        assert!(report.contains("report::test::test_report"));
+
+        // Payloads are shown based on their type, not on their `Debug` output.
+        let err = PLD_ERROR.error_with_payload(UnitLike);
+        assert!(err.report().to_string().contains("  payload:    ()\n"));
+    }
+
+    #[test]
+    fn test_report_panic_hook() {
+        set_report_panic_hook();
+
+        let err = PLD_ERROR.error_with_payload(UnitLike);
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| panic::panic_any(err))).unwrap_err();
+        let err = panic_error(&*payload).unwrap();
+        assert!(err.has_kind(PLD_ERROR.kind_id()));
+        assert!(err.downcast_payload_ref::<UnitLike>().is_some());
+
+        let shared = SharedError::new(PLD_ERROR.error_with_payload(UnitLike));
+        let payload =
+            panic::catch_unwind(AssertUnwindSafe(|| panic::panic_any(shared))).unwrap_err();
+        let err = panic_error(&*payload).unwrap();
+        assert!(err.has_kind(PLD_ERROR.kind_id()));
+
+        let payload = panic::catch_unwind(|| panic!("plain panic")).unwrap_err();
+        assert!(panic_error(&*payload).is_none());
+    }
+}
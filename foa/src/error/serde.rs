@@ -1,12 +1,18 @@
 use super::{
-    static_str::StaticStr, BasicKind, Error, JserBoxError, KindId, KindTypeInfo, NullError,
-    Payload, Props, Result, SendSyncStaticError, Tag, LIB_DEPENDENCY_TAG,
+    registered_kind, registered_tag, static_str::StaticStr, BasicKind, BoxPayload, DeserTrail,
+    Error, ErrorMeta, JserBoxError, KindId, KindTypeInfo, NullError, Payload, Props, Result,
+    SendSyncStaticError, SharedError, StdBoxError, Tag, Trail, LIB_DEPENDENCY_TAG, REMOTE_TAG,
 };
+use crate::{context::ErrCtx, string};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
+    any::type_name,
+    backtrace::Backtrace,
     collections::BTreeMap,
     error::Error as StdError,
     fmt::{Debug, Display},
+    iter::successors,
+    ops::Deref,
 };
 
 //===========================
@@ -20,6 +26,21 @@ pub struct SerError<PLD = Box<()>, SRC = Box<NullError>> {
     pub(super) props: Props,
     pub(super) payload: Option<PLD>,
     pub(super) src: Option<SRC>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) ref_id: Option<String>,
+    #[serde(skip_serializing_if = "Trail::is_empty")]
+    pub(super) trail: Trail,
+    /// Source location where the error was created, as `file:line:column`, included with
+    /// [`StringSpec::Location`](super::StringSpec::Location).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) location: Option<String>,
+    /// Time when the error was created, in RFC 3339 format, included with
+    /// [`StringSpec::CreatedAt`](super::StringSpec::CreatedAt).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) created_at: Option<String>,
+    /// Errors in the source chain, included with [`StringSpec::SourceChain`](super::StringSpec::SourceChain).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(super) source_chain: Vec<SerSource>,
     pub(super) other: BTreeMap<&'static str, String>,
 }
 
@@ -48,9 +69,65 @@ impl<PLD, SRC> SerError<PLD, SRC> {
         self.src.as_ref()
     }
 
+    pub fn ref_id(&self) -> Option<&str> {
+        self.ref_id.as_deref()
+    }
+
+    pub fn trail(&self) -> &Trail {
+        &self.trail
+    }
+
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<&str> {
+        self.created_at.as_deref()
+    }
+
+    pub fn source_chain(&self) -> &[SerSource] {
+        &self.source_chain
+    }
+
+    /// See [`Error::was_kind`].
+    pub fn was_kind(&self, kind: &KindId) -> bool {
+        self.kind_id == kind || self.trail.contains_kind(kind)
+    }
+
     pub fn other(&self) -> &BTreeMap<&'static str, String> {
         &self.other
     }
+
+    /// Returns `self` with its payload, if any, mapped by `f`.
+    pub fn map_payload<T>(self, f: impl FnOnce(PLD) -> T) -> SerError<T, SRC> {
+        SerError {
+            kind_id: self.kind_id,
+            msg: self.msg,
+            tag: self.tag,
+            props: self.props,
+            payload: self.payload.map(f),
+            src: self.src,
+            ref_id: self.ref_id,
+            trail: self.trail,
+            location: self.location,
+            created_at: self.created_at,
+            source_chain: self.source_chain,
+            other: self.other,
+        }
+    }
+
+    /// Returns `self` with `msg` replaced by the message template localized by `CTX` for `kind_id`,
+    /// interpolated with `props`. `self` is returned unchanged if `CTX` has no localized template
+    /// for the kind.
+    pub fn localized<CTX: ErrCtx>(self) -> Self {
+        match string::localized::<CTX>(self.kind_id.0) {
+            Some(raw_msg) => Self {
+                msg: self.props.safe_interpolated(raw_msg).into(),
+                ..self
+            },
+            None => self,
+        }
+    }
 }
 
 impl<PLD, SRC> Display for SerError<PLD, SRC> {
@@ -78,6 +155,60 @@ where
     }
 }
 
+/// Serializable representation of an error in the source chain of a [`SerError`].
+///
+/// `type_name` is the name of the error's type if known, i.e., for [`StdBoxError`]s and foa errors, or
+/// the name of `dyn Error` otherwise. For foa errors, `kind_id`, `tag`, and `props` are also included. Typed
+/// errors ([`TypedError`](super::TypedError)s) are recognized as foa errors if boxed with
+/// `StdBoxError::from`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct SerSource {
+    pub type_name: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind_id: Option<&'static KindId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<&'static Tag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub props: Option<Props>,
+}
+
+impl SerSource {
+    fn new(err: &(dyn StdError + 'static)) -> Self {
+        // Nested boxes, e.g., the sources of errors of kind `BasicKind<StdBoxError>`, are unwrapped.
+        let mut inner = err;
+        let mut boxed_type_name = None;
+        while let Some(boxed) = inner.downcast_ref::<StdBoxError>() {
+            boxed_type_name = Some(boxed.type_name());
+            inner = boxed.as_dyn_std_error();
+        }
+        let type_name = boxed_type_name.unwrap_or(if inner.is::<Error>() {
+            type_name::<Error>()
+        } else if inner.is::<SharedError>() {
+            type_name::<SharedError>()
+        } else {
+            type_name::<dyn StdError>()
+        });
+        let foa_err = inner
+            .downcast_ref::<Error>()
+            .or_else(|| inner.downcast_ref::<SharedError>().map(Deref::deref));
+        Self {
+            type_name,
+            message: err.to_string(),
+            kind_id: foa_err.map(|e| e.kind_id()),
+            tag: foa_err.map(|e| e.tag()),
+            props: foa_err.map(|e| e.props().clone()),
+        }
+    }
+}
+
+/// Returns the [`SerSource`]s of the errors in the source chain of `err`, excluding `err` itself.
+pub fn ser_source_chain(err: &dyn StdError) -> Vec<SerSource> {
+    successors(err.source(), |&e| e.source())
+        .map(SerSource::new)
+        .collect()
+}
+
 // endregion:   --- SerError
 
 //===========================
@@ -86,6 +217,13 @@ where
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DeserTag(pub String);
 
+impl DeserTag {
+    /// Returns the known [`Tag`] with this name, if any (see [`super::registered_tag`]).
+    pub fn resolve(&self) -> Option<&'static Tag> {
+        registered_tag(&self.0)
+    }
+}
+
 // endregion:   --- DeserTag
 
 //===========================
@@ -94,15 +232,23 @@ pub struct DeserTag(pub String);
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DeserKindId(pub String);
 
+impl DeserKindId {
+    /// Returns the [`KindId`] of the registered kind with this name, if any (see [`super::register_kinds`]).
+    pub fn resolve(&self) -> Option<&'static KindId> {
+        registered_kind(&self.0).map(|kind| kind.kind_id())
+    }
+}
+
 // endregion:   --- DeserKindId
 
 //===========================
 // region:      --- DeserError
 
-static JSON_DESER_ERROR: BasicKind<serde_json::Error> =
+pub(super) static JSON_DESER_ERROR: BasicKind<serde_json::Error> =
     BasicKind::new("JSON_DESER_ERROR", None, &LIB_DEPENDENCY_TAG);
 
 impl From<serde_json::Error> for Error {
+    #[track_caller]
     fn from(value: serde_json::Error) -> Self {
         JSON_DESER_ERROR.error_with_src(value)
     }
@@ -116,6 +262,14 @@ pub struct DeserError<PLD = Box<()>, SRC = Box<NullError>> {
     pub props: Props,
     pub payload: Option<PLD>,
     pub src: Option<SRC>,
+    #[serde(default)]
+    pub ref_id: Option<String>,
+    #[serde(default)]
+    pub trail: DeserTrail,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
     pub other: BTreeMap<String, String>,
 }
 
@@ -156,6 +310,90 @@ impl DeserError {
     }
 }
 
+/// Kind of errors reconstructed from a [`DeserError`] whose kind is not registered
+/// (see [`super::register_kinds`]).
+pub static UNKNOWN_REMOTE_ERROR: BasicKind =
+    BasicKind::new("UNKNOWN_REMOTE_ERROR", None, &REMOTE_TAG);
+
+impl<PLD, SRC: SendSyncStaticError> DeserError<PLD, Box<SRC>> {
+    /// Returns the error reconstructed from `self` without a payload, and `self`'s payload.
+    #[track_caller]
+    fn into_error_priv(self) -> (Error, Option<PLD>) {
+        let mut props = self.props;
+        let (kind_id, tag) = match registered_kind(&self.kind_id.0) {
+            Some(kind) => (kind.kind_id(), kind.tag()),
+            None => {
+                props
+                    .pairs
+                    .push(("remote_kind_id".to_owned(), self.kind_id.0.clone().into()));
+                let tag = self.tag.resolve().unwrap_or_else(|| {
+                    props
+                        .pairs
+                        .push(("remote_tag".to_owned(), self.tag.0.into()));
+                    UNKNOWN_REMOTE_ERROR.tag()
+                });
+                (UNKNOWN_REMOTE_ERROR.kind_id(), tag)
+            }
+        };
+        if let Some(location) = self.location {
+            props
+                .pairs
+                .push(("remote_location".to_owned(), location.into()));
+        }
+        if let Some(created_at) = self.created_at {
+            props
+                .pairs
+                .push(("remote_created_at".to_owned(), created_at.into()));
+        }
+        let src = self.src.map(StdBoxError::from_box);
+        let mut meta = ErrorMeta::new(Backtrace::disabled(), self.ref_id);
+        meta.trail = self.trail.resolve();
+        let err = Error::with_msg_string(kind_id, self.msg, tag, props, (), src, meta);
+        (err, self.payload)
+    }
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> DeserError<Box<PLD>, Box<SRC>> {
+    /// Reconstructs an [`Error`] from `self`, resolving its kind and tag through the kind registry
+    /// (see [`super::register_kinds`]). The error's location is the caller's; the remote error's location
+    /// and creation time, if present, are added to its props as `remote_location` and `remote_created_at`.
+    ///
+    /// If the kind is not registered, the resulting error is of kind [`UNKNOWN_REMOTE_ERROR`], with
+    /// the original kind id added to its props as `remote_kind_id` and the original tag resolved
+    /// as per [`DeserTag::resolve`] or added to its props as `remote_tag` if unknown.
+    #[track_caller]
+    pub fn into_error(self) -> Error {
+        let (mut err, payload) = self.into_error_priv();
+        if let Some(payload) = payload {
+            err.payload = BoxPayload::new(*payload);
+        }
+        err
+    }
+}
+
+impl<SRC: SendSyncStaticError> DeserError<(), Box<SRC>> {
+    /// Same as [`DeserError::into_error`] for errors deserialized without a payload, e.g., with
+    /// [`DeserError::deser_src_for_kind`].
+    #[track_caller]
+    pub fn into_error(self) -> Error {
+        self.into_error_priv().0
+    }
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> From<DeserError<Box<PLD>, Box<SRC>>> for Error {
+    #[track_caller]
+    fn from(value: DeserError<Box<PLD>, Box<SRC>>) -> Self {
+        value.into_error()
+    }
+}
+
+impl<SRC: SendSyncStaticError> From<DeserError<(), Box<SRC>>> for Error {
+    #[track_caller]
+    fn from(value: DeserError<(), Box<SRC>>) -> Self {
+        value.into_error()
+    }
+}
+
 impl<PLD, SRC> Display for DeserError<PLD, SRC> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.msg)
@@ -191,10 +429,14 @@ impl<PLD, SRC> From<SerError<PLD, SRC>> for DeserError<PLD, SRC> {
         Self {
             kind_id: DeserKindId(value.kind_id().0.to_owned()),
             msg: value.msg().to_owned(),
-            tag: DeserTag(value.tag().0.to_owned()),
+            tag: DeserTag(value.tag().name().to_owned()),
             props: value.props,
             payload: value.payload,
             src: value.src,
+            ref_id: value.ref_id,
+            trail: DeserTrail::from(&value.trail),
+            location: value.location,
+            created_at: value.created_at,
             other,
         }
     }
@@ -204,5 +446,189 @@ impl<PLD, SRC> From<SerError<PLD, SRC>> for DeserError<PLD, SRC> {
 
 #[cfg(test)]
 mod test {
-    // See `dev_support::deser_example`
+    // See also `dev_support::deser_example`
+
+    use super::*;
+    use crate::error::{
+        register_kinds, BasicKind, FullKind, PropsKind, StringSpec, TrivialError, TypedError,
+    };
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Pld(String);
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Src(String);
+
+    impl Display for Src {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl StdError for Src {}
+
+    static FOO_ERROR: FullKind<Pld, 1, Src> = FullKind::new_with_props(
+        "SERDE_FOO_ERROR",
+        Some("foo message: {xyz}"),
+        &FOO_TAG,
+        ["xyz"],
+    );
+
+    fn make_json_string() -> String {
+        let err = FOO_ERROR
+            .error_with_values_payload_src(
+                ["hi there!"],
+                Pld("foo-payload".into()),
+                Src("dummy".into()),
+            )
+            .downcast_payload::<Pld>()
+            .unwrap();
+        let ser_err = err.into_sererror_with_payload([StringSpec::Location, StringSpec::CreatedAt]);
+        serde_json::to_string(&ser_err).unwrap()
+    }
+
+    #[test]
+    fn test_into_error_registered() {
+        register_kinds(&[&FOO_ERROR]).unwrap();
+
+        let json_string = make_json_string();
+        let deser_err =
+            DeserError::deser_payload_for_kind(&FOO_ERROR, json_string.clone()).unwrap();
+        let remote_location = deser_err.location.clone().unwrap();
+        let remote_created_at = deser_err.created_at.clone().unwrap();
+        let (err, line) = (Error::from(deser_err), line!());
+
+        assert!(err.has_kind(FOO_ERROR.kind_id()));
+        assert_eq!(err.tag(), &FOO_TAG);
+        assert_eq!(err.to_string(), "foo message: hi there!");
+        assert_eq!(err.props().prop_str("xyz"), Some("hi there!"));
+        assert_eq!(
+            err.props().prop_str("remote_location"),
+            Some(remote_location.as_str())
+        );
+        assert_eq!(
+            err.props().prop_str("remote_created_at"),
+            Some(remote_created_at.as_str())
+        );
+        assert_eq!(err.location().line(), line);
+
+        let err = err.downcast_payload_for_kind::<FullKind<Pld, 1>>().unwrap();
+        assert_eq!(err.payload().as_ref(), &Pld("foo-payload".into()));
+        assert!(err.src().is_none());
+
+        let json_string = json_string.replace(r#""payload":"foo-payload""#, r#""payload":null"#);
+        let deser_err = DeserError::deser_src_for_kind(&FOO_ERROR, json_string).unwrap();
+        let err = deser_err.into_error();
+        assert!(err.has_kind(FOO_ERROR.kind_id()));
+        assert_eq!(err.downcast_payload_ref::<()>(), Some(&()));
+    }
+
+    #[test]
+    fn test_into_error_unknown() {
+        let json_string = make_json_string()
+            .replace("SERDE_FOO_ERROR", "SERDE_BAR_ERROR")
+            .replace(r#""tag":"FOO""#, r#""tag":"SERDE_BAR""#);
+        let deser_err: DeserError<Box<serde_json::Value>> =
+            serde_json::from_str(&json_string).unwrap();
+        let err = deser_err.into_error();
+
+        assert!(err.has_kind(UNKNOWN_REMOTE_ERROR.kind_id()));
+        assert_eq!(err.tag(), &REMOTE_TAG);
+        assert_eq!(err.to_string(), "foo message: hi there!");
+        assert_eq!(
+            err.props().prop_str("remote_kind_id"),
+            Some("SERDE_BAR_ERROR")
+        );
+        assert_eq!(err.props().prop_str("remote_tag"), Some("SERDE_BAR"));
+        assert_eq!(
+            err.downcast_payload_ref::<serde_json::Value>(),
+            Some(&serde_json::json!("foo-payload"))
+        );
+
+        let json_string = json_string.replace(r#""payload":"foo-payload""#, r#""payload":null"#);
+        let deser_err = DeserError::deser_src_for_kind(&UNKNOWN_REMOTE_ERROR, json_string).unwrap();
+        let err = deser_err.into_error();
+        assert!(err.has_kind(UNKNOWN_REMOTE_ERROR.kind_id()));
+        assert_eq!(err.tag(), &REMOTE_TAG);
+        assert_eq!(err.downcast_payload_ref::<()>(), Some(&()));
+    }
+
+    static INNER_ERROR: PropsKind<1, TrivialError> = PropsKind::new_with_props(
+        "SERDE_INNER_ERROR",
+        Some("inner message: {abc}"),
+        &FOO_TAG,
+        ["abc"],
+    );
+
+    static OUTER_ERROR: BasicKind<StdBoxError> =
+        BasicKind::new("SERDE_OUTER_ERROR", Some("outer message"), &FOO_TAG);
+
+    #[test]
+    fn test_source_chain() {
+        let inner = INNER_ERROR.error_with_values_src(["x"], TrivialError("dummy"));
+        let err = OUTER_ERROR.error_with_src(StdBoxError::new(inner));
+
+        let ser_err = err.to_sererror_no_payload_src([]);
+        assert!(ser_err.source_chain().is_empty());
+        assert!(!serde_json::to_value(&ser_err)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .contains_key("source_chain"));
+
+        let ser_err = err.to_sererror_no_payload_src([StringSpec::SourceChain]);
+        assert!(ser_err.other().is_empty());
+        let chain = ser_err.source_chain();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].type_name, type_name::<Error>());
+        assert_eq!(chain[0].message, "inner message: x");
+        assert_eq!(chain[0].kind_id, Some(INNER_ERROR.kind_id()));
+        assert_eq!(chain[0].tag, Some(&FOO_TAG));
+        assert_eq!(chain[0].props.as_ref().unwrap().prop_str("abc"), Some("x"));
+        assert_eq!(chain[1].type_name, type_name::<TrivialError>());
+        assert_eq!(chain[1].message, "dummy");
+        assert_eq!(chain[1].kind_id, None);
+
+        let json = serde_json::to_value(&ser_err).unwrap();
+        assert_eq!(json["source_chain"][0]["kind_id"], "SERDE_INNER_ERROR");
+        assert_eq!(json["source_chain"][1]["message"], "dummy");
+        assert!(json["source_chain"][1].get("props").is_none());
+
+        let json_string = err.as_fmt().multi_speced_string([StringSpec::SourceChain]);
+        let json: serde_json::Value = serde_json::from_str(&json_string).unwrap();
+        assert_eq!(json[0]["message"], "inner message: x");
+
+        let typed = INNER_ERROR.typed_error_with_values_src(["y"], TrivialError("typed dummy"));
+        let chain = typed.ser_source_chain();
+        assert_eq!(chain.len(), 1);
+        assert_eq!(chain[0].type_name, type_name::<TrivialError>());
+
+        let downcast = INNER_ERROR
+            .error_with_values_src(["z"], TrivialError("downcast dummy"))
+            .downcast_payload_src_for_kind(&INNER_ERROR)
+            .unwrap();
+        let chain = downcast.ser_source_chain();
+        assert_eq!(chain[0].type_name, type_name::<TrivialError>());
+        assert_eq!(
+            downcast.erase().ser_source_chain()[0].type_name,
+            type_name::<TrivialError>()
+        );
+
+        let err = OUTER_ERROR.error_with_src(StdBoxError::from(typed));
+        let chain = err.ser_source_chain();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(
+            chain[0].type_name,
+            type_name::<TypedError<PropsKind<1, TrivialError>>>()
+        );
+        assert_eq!(chain[0].message, "inner message: y");
+        assert_eq!(chain[0].kind_id, Some(INNER_ERROR.kind_id()));
+        assert_eq!(chain[0].tag, Some(&FOO_TAG));
+        assert_eq!(chain[0].props.as_ref().unwrap().prop_str("abc"), Some("y"));
+        assert_eq!(chain[1].type_name, type_name::<TrivialError>());
+        let boxed = err.downcast_src_ref::<StdBoxError>().unwrap();
+        assert!(boxed.as_dyn_std_error().is::<Error>());
+    }
 }
@@ -0,0 +1,173 @@
+use super::{BoxPayload, Error, ErrorMeta, StdBoxError, Trail, WithBacktrace};
+use std::{
+    backtrace::Backtrace,
+    error::Error as StdError,
+    fmt::{Debug, Display},
+    ops::Deref,
+    panic::Location,
+    sync::Arc,
+    time::SystemTime,
+};
+
+//===========================
+// region:      --- SharedError
+
+/// Cheaply cloneable form of [`Error`] whose clones share the error, including its payload, source, and
+/// backtrace. Can be used, e.g., to cache a failed result or to hand the same failure to multiple waiters.
+///
+/// Dereferences to the shared [`Error`], so its accessors and by-reference downcast methods
+/// (e.g., [`Error::downcast_payload_ref`] and [`Error::downcast_src_ref`]) are available. The owned form
+/// can be recovered with [`Self::try_into_error`] once the other clones are dropped, and an owned copy can be
+/// obtained at any time with [`Self::to_error`].
+#[derive(Debug, Clone)]
+pub struct SharedError(Arc<Error>);
+
+impl SharedError {
+    pub fn new(error: Error) -> Self {
+        Self(Arc::new(error))
+    }
+
+    /// Returns the owned [`Error`] if `self` is its only clone, or `Err(self)` otherwise.
+    pub fn try_into_error(self) -> Result<Error, Self> {
+        Arc::try_unwrap(self.0).map_err(Self)
+    }
+
+    /// Returns an owned [`Error`] with the same kind, message, tag, props, ref id, trail, location, and
+    /// creation time as the shared error, even if other clones exist, e.g., for each waiter of a single-flight
+    /// group. Its source is a clone of `self`, through which the shared payload, source, and backtrace remain
+    /// available without being copied; it has no payload and no backtrace of its own.
+    pub fn to_error(&self) -> Error {
+        let err = self.0.as_ref();
+        let mut meta = ErrorMeta::new(Backtrace::disabled(), err.meta.ref_id.clone());
+        meta.trail = err.meta.trail.clone();
+        meta.location = err.meta.location;
+        meta.created_at = err.meta.created_at;
+        Error {
+            kind_id: err.kind_id,
+            msg: err.msg.clone(),
+            tag: err.tag,
+            props: err.props.clone(),
+            payload: BoxPayload::new(()),
+            src: Some(StdBoxError::new(self.clone())),
+            meta: Box::new(meta),
+        }
+    }
+
+    /// Returns true if `self` and `other` are clones of the same error.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl From<Error> for SharedError {
+    fn from(value: Error) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Deref for SharedError {
+    type Target = Error;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Error> for SharedError {
+    fn as_ref(&self) -> &Error {
+        &self.0
+    }
+}
+
+impl Display for SharedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl StdError for SharedError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.source()
+    }
+}
+
+impl WithBacktrace for SharedError {
+    fn backtrace(&self) -> &Backtrace {
+        self.0.backtrace()
+    }
+
+    fn trail(&self) -> Option<&Trail> {
+        WithBacktrace::trail(self.0.as_ref())
+    }
+
+    fn location(&self) -> Option<&'static Location<'static>> {
+        Some(self.0.location())
+    }
+
+    fn created_at(&self) -> Option<SystemTime> {
+        Some(self.0.created_at())
+    }
+}
+
+// endregion:   --- SharedError
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{FullKind, Tag, TrivialError};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static FOO_ERROR: FullKind<u32, 1, TrivialError> =
+        FullKind::new_with_props("SHARED_FOO_ERROR", Some("foo {abc}"), &FOO_TAG, ["abc"]);
+
+    #[test]
+    fn test_shared_error() {
+        let err = FOO_ERROR.error_with_values_payload_src(["x"], 42, TrivialError("trivial"));
+        let shared = SharedError::from(err);
+        let clone = shared.clone();
+        assert!(shared.ptr_eq(&clone));
+
+        assert!(clone.has_kind(FOO_ERROR.kind_id()));
+        assert_eq!(clone.to_string(), "foo x");
+        assert_eq!(clone.downcast_payload_ref::<u32>(), Some(&42));
+        assert_eq!(
+            clone.downcast_src_ref::<TrivialError>(),
+            Some(&TrivialError("trivial"))
+        );
+        assert_eq!(clone.source().unwrap().to_string(), "trivial");
+
+        let shared = shared.try_into_error().unwrap_err();
+        drop(clone);
+        let err = shared.try_into_error().unwrap();
+        let err = err.downcast_payload_src_for_kind(&FOO_ERROR).unwrap();
+        assert_eq!(*err.payload(), Box::new(42));
+
+        let _: StdBoxError = StdBoxError::new(SharedError::new(err.erase()));
+
+        let shared = SharedError::from(
+            FOO_ERROR
+                .error_with_values_payload_src(["y"], 7, TrivialError("trivial"))
+                .with_breadcrumb("in foo"),
+        );
+        let clone = shared.clone();
+        let owned = shared.to_error();
+        let original: &Error = &shared;
+        assert!(owned.has_kind(FOO_ERROR.kind_id()));
+        assert_eq!(owned.to_string(), "foo y");
+        assert_eq!(owned.tag(), original.tag());
+        assert_eq!(owned.props(), original.props());
+        assert_eq!(owned.trail(), original.trail());
+        assert_eq!(owned.location(), original.location());
+        assert_eq!(owned.created_at(), original.created_at());
+
+        let src = owned.downcast_src_ref::<SharedError>().unwrap();
+        assert!(src.ptr_eq(&clone));
+        assert_eq!(src.downcast_payload_ref::<u32>(), Some(&7));
+        assert_eq!(owned.source().unwrap().to_string(), "foo y");
+        assert_eq!(
+            owned.source().unwrap().source().unwrap().to_string(),
+            "trivial"
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use super::{
+    full_kind::check_prop_names, BacktraceSpec, Error, FullKind, KindId, KindInfo, KindTypeInfo,
+    NullError, Payload, PropValue, SendSyncStaticError, StdBoxError, Tag,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+//===========================
+// region:      --- KindProps
+
+/// Struct whose named fields are the props of a [`StructKind`]. Usually implemented with the
+/// [`kind_props`](crate::kind_props) macro.
+pub trait KindProps {
+    /// Names of the props, in field order. Names of protected props start with `'!'`.
+    const PROP_NAMES: &'static [&'static str];
+
+    /// Returns the values of the props, in the same order as [`Self::PROP_NAMES`].
+    fn into_values(self) -> Vec<PropValue>;
+}
+
+/// Defines a struct and implements [`KindProps`](crate::error::KindProps) for it. Prop names are the field
+/// names, and fields marked `#[protected]` become protected props, whose names are prefixed with `'!'`
+/// (so they are referenced as `{!field}` in kind messages). Fields may have other attributes, including doc
+/// comments, before or after the marker. Field types must implement
+/// `Into<`[`PropValue`](crate::error::PropValue)`>`.
+///
+/// ```
+/// use foa::{error::{StructKind, Tag}, kind_props};
+///
+/// kind_props! {
+///     pub struct FooProps {
+///         /// Used in the message.
+///         pub abc: String,
+///         #[protected]
+///         pub email: String,
+///     }
+/// }
+///
+/// static FOO_TAG: Tag = Tag::new("FOO");
+///
+/// static FOO_ERROR: StructKind<FooProps> =
+///     StructKind::new("FOO_ERROR", Some("foo {abc} {!email}"), &FOO_TAG);
+///
+/// let err = FOO_ERROR.error_with_props(FooProps {
+///     abc: "hi there".into(),
+///     email: "foo@example.com".into(),
+/// });
+/// assert_eq!(err.props().prop_str("abc"), Some("hi there"));
+/// assert_eq!(err.props().prop_str("!email"), Some("foo@example.com"));
+/// ```
+#[macro_export]
+macro_rules! kind_props {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$($fattr:tt)*])*
+                $fvis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $crate::kind_props!(
+            @struct [$(#[$attr])* $vis struct $name] []
+            $([$(#[$($fattr)*])*] [$fvis $field: $ty])*
+        );
+
+        impl $crate::error::KindProps for $name {
+            const PROP_NAMES: &'static [&'static str] =
+                &[$($crate::kind_props!(@name [$(#[$($fattr)*])*] $field)),*];
+
+            fn into_values(self) -> Vec<$crate::error::PropValue> {
+                vec![$(self.$field.into()),*]
+            }
+        }
+    };
+
+    // Emits the struct, with the `#[protected]` markers removed from the field attributes.
+    (@struct [$($head:tt)*] [$($done:tt)*]) => {
+        $($head)* { $($done)* }
+    };
+
+    (@struct $head:tt [$($done:tt)*] [#[protected] $($attrs:tt)*] $field:tt $($rest:tt)*) => {
+        $crate::kind_props!(@struct $head [$($done)*] [$($attrs)*] $field $($rest)*);
+    };
+
+    (@struct $head:tt [$($done:tt)*] [#[$($attr:tt)*] $($attrs:tt)*] $field:tt $($rest:tt)*) => {
+        $crate::kind_props!(@struct $head [$($done)* #[$($attr)*]] [$($attrs)*] $field $($rest)*);
+    };
+
+    (@struct $head:tt [$($done:tt)*] [] [$($field:tt)*] $($rest:tt)*) => {
+        $crate::kind_props!(@struct $head [$($done)* $($field)*,] $($rest)*);
+    };
+
+    // Emits the prop name of a field, prefixed with `'!'` if the field is marked `#[protected]`.
+    (@name [] $field:ident) => {
+        stringify!($field)
+    };
+
+    (@name [#[protected] $($attrs:tt)*] $field:ident) => {
+        concat!("!", stringify!($field))
+    };
+
+    (@name [#[$($attr:tt)*] $($attrs:tt)*] $field:ident) => {
+        $crate::kind_props!(@name [$($attrs)*] $field)
+    };
+}
+
+// endregion:   --- KindProps
+
+//===========================
+// region:      --- StructKind
+
+/// Error kind whose props are defined by a [`KindProps`] struct instead of a positional array of values.
+///
+/// See [`Self::new`] and [`FullKind::with_props`].
+pub struct StructKind<P, PLD: Payload = (), SRC: SendSyncStaticError = NullError> {
+    kind: FullKind<PLD, 0, SRC>,
+    _props: PhantomData<fn() -> P>,
+}
+
+impl<PLD: Payload, SRC: SendSyncStaticError> FullKind<PLD, 0, SRC> {
+    /// Converts `self` into a [`StructKind`] with props defined by `P`. Fails const evaluation, and therefore
+    /// the build for static kinds, if the `{name}` placeholders in the kind's message are not exactly
+    /// `P`'s prop names.
+    pub const fn with_props<P: KindProps>(self) -> StructKind<P, PLD, SRC> {
+        check_prop_names(self.msg, P::PROP_NAMES);
+        StructKind {
+            kind: self,
+            _props: PhantomData,
+        }
+    }
+}
+
+impl<P: KindProps, PLD: Payload, SRC: SendSyncStaticError> StructKind<P, PLD, SRC> {
+    /// Creates a kind with props defined by `P`. Fails const evaluation, and therefore the build for static
+    /// kinds, if the `{name}` placeholders in `msg` are not exactly `P`'s prop names.
+    pub const fn new(name: &'static str, msg: Option<&'static str>, tag: &'static Tag) -> Self {
+        check_prop_names(msg, P::PROP_NAMES);
+        Self {
+            kind: FullKind::new_priv(name, msg, tag, []),
+            _props: PhantomData,
+        }
+    }
+
+    pub const fn with_backtrace(self, backtrace_spec: BacktraceSpec) -> Self {
+        Self {
+            kind: self.kind.with_backtrace(backtrace_spec),
+            _props: PhantomData,
+        }
+    }
+
+    pub const fn with_ref_id(self) -> Self {
+        Self {
+            kind: self.kind.with_ref_id(),
+            _props: PhantomData,
+        }
+    }
+
+    pub const fn kind_id(&self) -> &KindId {
+        self.kind.kind_id()
+    }
+
+    pub const fn msg(&self) -> &'static str {
+        self.kind.msg()
+    }
+
+    pub const fn tag(&self) -> &'static Tag {
+        self.kind.tag()
+    }
+
+    pub const fn prop_names(&self) -> &'static [&'static str] {
+        P::PROP_NAMES
+    }
+
+    pub const fn backtrace_spec(&self) -> BacktraceSpec {
+        self.kind.backtrace_spec()
+    }
+
+    pub const fn has_ref_id(&self) -> bool {
+        self.kind.has_ref_id()
+    }
+
+    #[track_caller]
+    fn error_priv(&'static self, props: P, payload: PLD, source: Option<StdBoxError>) -> Error {
+        let pairs = P::PROP_NAMES
+            .iter()
+            .zip(props.into_values())
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        self.kind.error_with_pairs_priv(pairs, payload, source)
+    }
+}
+
+impl<P: KindProps> StructKind<P> {
+    #[track_caller]
+    pub fn error_with_props(&'static self, props: P) -> Error {
+        self.error_priv(props, (), None)
+    }
+}
+
+impl<P: KindProps, SRC: SendSyncStaticError> StructKind<P, (), SRC> {
+    #[track_caller]
+    pub fn error_with_props_src(&'static self, props: P, source: SRC) -> Error {
+        self.error_priv(props, (), Some(StdBoxError::new(source)))
+    }
+}
+
+impl<P: KindProps, PLD: Payload> StructKind<P, PLD> {
+    #[track_caller]
+    pub fn error_with_props_payload(&'static self, props: P, payload: PLD) -> Error {
+        self.error_priv(props, payload, None)
+    }
+}
+
+impl<P: KindProps, PLD: Payload, SRC: SendSyncStaticError> StructKind<P, PLD, SRC> {
+    #[track_caller]
+    pub fn error_with_props_payload_src(
+        &'static self,
+        props: P,
+        payload: PLD,
+        source: SRC,
+    ) -> Error {
+        self.error_priv(props, payload, Some(StdBoxError::new(source)))
+    }
+}
+
+impl<P: KindProps, PLD: Payload, SRC: SendSyncStaticError> KindInfo for StructKind<P, PLD, SRC> {
+    fn kind_id(&self) -> &KindId {
+        Self::kind_id(self)
+    }
+
+    fn tag(&self) -> &'static Tag {
+        Self::tag(self)
+    }
+
+    fn msg(&self) -> &'static str {
+        Self::msg(self)
+    }
+
+    fn prop_names(&self) -> &[&'static str] {
+        P::PROP_NAMES
+    }
+
+    fn has_ref_id(&self) -> bool {
+        Self::has_ref_id(self)
+    }
+}
+
+impl<P: KindProps, PLD: Payload, SRC: SendSyncStaticError> KindTypeInfo
+    for StructKind<P, PLD, SRC>
+{
+    type Pld = PLD;
+    type Src = SRC;
+}
+
+impl<P: KindProps, PLD: Payload, SRC: SendSyncStaticError> Debug for StructKind<P, PLD, SRC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StructKind")
+            .field("kind", &self.kind)
+            .field("prop_names", &P::PROP_NAMES)
+            .finish()
+    }
+}
+
+// endregion:   --- StructKind
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BasicKind, TrivialError};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    kind_props! {
+        #[derive(Debug, Clone)]
+        struct FooProps {
+            /// Number of retries.
+            retries: u32,
+            /// Email of the user.
+            #[protected]
+            #[allow(unused)]
+            email: String,
+            name: &'static str,
+        }
+    }
+
+    static FOO_ERROR: StructKind<FooProps, (), TrivialError> = BasicKind::new(
+        "STRUCT_FOO_ERROR",
+        Some("{name}: retry {retries} for {!email}"),
+        &FOO_TAG,
+    )
+    .with_props()
+    .with_ref_id();
+
+    #[test]
+    fn test_struct_kind() {
+        assert_eq!(FOO_ERROR.prop_names(), ["retries", "!email", "name"]);
+
+        let props = FooProps {
+            retries: 2,
+            email: "foo@example.com".into(),
+            name: "foo",
+        };
+        let err = FOO_ERROR.error_with_props_src(props, TrivialError("trivial"));
+        assert!(err.has_kind(FOO_ERROR.kind_id()));
+        assert!(err.ref_id().is_some());
+        assert_eq!(err.props().prop_i64("retries"), Some(2));
+        assert_eq!(err.props().prop_str("!email"), Some("foo@example.com"));
+        assert_eq!(err.props().prop_str("name"), Some("foo"));
+
+        assert!(format!("{FOO_ERROR:?}").starts_with("StructKind { kind: FullKind"));
+        let err = err.downcast_payload_src_for_kind(&FOO_ERROR).unwrap();
+        assert_eq!(**err.src().unwrap(), TrivialError("trivial"));
+    }
+}
@@ -1,13 +1,69 @@
-//! [`ErrorTag`] instances that are commonly used.
+//! [`Tag`] instances that are commonly used.
+//!
+//! [`CLIENT_TAG`] and [`TRANSIENT_TAG`] head families of standard tags (see [`Tag::is_a`]).
 
 use super::Tag;
 
-pub static INTERNAL_TAG: Tag = Tag("INTERNAL");
+pub static INTERNAL_TAG: Tag = Tag::new("INTERNAL");
 
-pub static RUNTIME_TAG: Tag = Tag("RUNTIME");
+pub static RUNTIME_TAG: Tag = Tag::new("RUNTIME");
 
-pub static VALIDATION_TAG: Tag = Tag("VALIDATION");
+pub static VALIDATION_TAG: Tag = Tag::new("VALIDATION").with_parent(&CLIENT_TAG);
 
-pub static UNEXPECTED_TAG: Tag = Tag("UNEXPECTED");
+pub static UNEXPECTED_TAG: Tag = Tag::new("UNEXPECTED");
 
-pub static LIB_DEPENDENCY_TAG: Tag = Tag("LIB_DEPENDENCY");
+pub static LIB_DEPENDENCY_TAG: Tag = Tag::new("LIB_DEPENDENCY");
+
+pub static REMOTE_TAG: Tag = Tag::new("REMOTE");
+
+pub static AGGREGATE_TAG: Tag = Tag::new("AGGREGATE");
+
+/// Errors caused by the client's request, e.g., invalid input or a missing resource.
+pub static CLIENT_TAG: Tag = Tag::new("CLIENT");
+
+/// A requested resource doesn't exist.
+pub static NOT_FOUND_TAG: Tag = Tag::new("NOT_FOUND").with_parent(&CLIENT_TAG);
+
+/// The request conflicts with the current state of a resource, e.g., a duplicate key.
+pub static CONFLICT_TAG: Tag = Tag::new("CONFLICT").with_parent(&CLIENT_TAG);
+
+/// The client is not authenticated or not authorized.
+pub static AUTH_TAG: Tag = Tag::new("AUTH").with_parent(&CLIENT_TAG);
+
+/// The client is authenticated but not allowed to perform the request.
+pub static FORBIDDEN_TAG: Tag = Tag::new("FORBIDDEN").with_parent(&AUTH_TAG);
+
+/// The client has sent too many requests.
+pub static RATE_LIMITED_TAG: Tag = Tag::new("RATE_LIMITED").with_parent(&CLIENT_TAG);
+
+/// Temporary failures that may succeed if retried, e.g., timeouts or an unavailable dependency.
+pub static TRANSIENT_TAG: Tag = Tag::new("TRANSIENT");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static OTHER_CLIENT_TAG: Tag = Tag::new("CLIENT");
+
+    static OTHER_NOT_FOUND_TAG: Tag = Tag::new("NOT_FOUND").with_parent(&OTHER_CLIENT_TAG);
+
+    #[test]
+    fn test_is_a() {
+        assert!(NOT_FOUND_TAG.is_a(&NOT_FOUND_TAG));
+        assert!(NOT_FOUND_TAG.is_a(&CLIENT_TAG));
+        assert!(VALIDATION_TAG.is_a(&CLIENT_TAG));
+        assert!(FORBIDDEN_TAG.is_a(&AUTH_TAG));
+        assert!(FORBIDDEN_TAG.is_a(&CLIENT_TAG));
+        assert!(RATE_LIMITED_TAG.is_a(&CLIENT_TAG));
+        assert!(!CLIENT_TAG.is_a(&NOT_FOUND_TAG));
+        assert!(!TRANSIENT_TAG.is_a(&CLIENT_TAG));
+        assert_ne!(&OTHER_CLIENT_TAG, &CLIENT_TAG);
+        assert!(!OTHER_NOT_FOUND_TAG.is_a(&CLIENT_TAG));
+        assert!(!NOT_FOUND_TAG.is_a(&OTHER_CLIENT_TAG));
+        assert_eq!(
+            CONFLICT_TAG.ancestors().map(Tag::name).collect::<Vec<_>>(),
+            ["CONFLICT", "CLIENT"]
+        );
+        assert_eq!(serde_json::to_string(&AUTH_TAG).unwrap(), "\"AUTH\"");
+    }
+}
@@ -0,0 +1,318 @@
+use super::{
+    BasicKind, DeserKindId, Error, KindId, Payload, PropValue, PropsKind, Result,
+    SendSyncStaticError,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, mem};
+
+//===========================
+// region:      --- Trail
+
+/// Context accumulated by an [`Error`] as it propagates: the kinds it had before its current kind, oldest
+/// first, and the breadcrumbs pushed along the way, in the order they were pushed.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct Trail {
+    pub(crate) kind_history: Vec<&'static KindId>,
+    pub(crate) breadcrumbs: Vec<String>,
+}
+
+impl Trail {
+    pub fn kind_history(&self) -> &[&'static KindId] {
+        &self.kind_history
+    }
+
+    pub fn breadcrumbs(&self) -> &[String] {
+        &self.breadcrumbs
+    }
+
+    /// Whether `kind` is in the kind history.
+    pub fn contains_kind(&self, kind: &KindId) -> bool {
+        self.kind_history.contains(&kind)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kind_history.is_empty() && self.breadcrumbs.is_empty()
+    }
+}
+
+/// Formats as, e.g., `kind_history=[DB_ERROR, READ_DAF_ERROR], breadcrumbs=[reading foo, in foo_sfl]`.
+impl Display for Trail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kinds = self
+            .kind_history
+            .iter()
+            .map(|kind_id| kind_id.0)
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "kind_history=[{}], breadcrumbs=[{}]",
+            kinds.join(", "),
+            self.breadcrumbs.join(", ")
+        )
+    }
+}
+
+/// Deserialized counterpart of [`Trail`].
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeserTrail {
+    pub kind_history: Vec<DeserKindId>,
+    pub breadcrumbs: Vec<String>,
+}
+
+impl DeserTrail {
+    /// Returns the corresponding [`Trail`]. Kinds that are not registered (see [`super::register_kinds`])
+    /// are omitted from the kind history.
+    pub fn resolve(self) -> Trail {
+        Trail {
+            kind_history: self
+                .kind_history
+                .iter()
+                .filter_map(DeserKindId::resolve)
+                .collect(),
+            breadcrumbs: self.breadcrumbs,
+        }
+    }
+}
+
+impl From<&Trail> for DeserTrail {
+    fn from(value: &Trail) -> Self {
+        Self {
+            kind_history: value
+                .kind_history
+                .iter()
+                .map(|kind_id| DeserKindId(kind_id.0.to_owned()))
+                .collect(),
+            breadcrumbs: value.breadcrumbs.clone(),
+        }
+    }
+}
+
+// endregion:   --- Trail
+
+//===========================
+// region:      --- Error context methods
+
+impl<PLD: Payload, SRC: SendSyncStaticError> Error<PLD, SRC> {
+    pub fn trail(&self) -> &Trail {
+        &self.meta.trail
+    }
+
+    /// Whether `kind` is the current kind of `self` or is in its kind history, e.g., because `self` was
+    /// transmuted from an error of kind `kind` (see [`super::TransmuterKind::transmute`]).
+    pub fn was_kind(&self, kind: &KindId) -> bool {
+        self.kind_id == kind || self.meta.trail.contains_kind(kind)
+    }
+
+    /// Returns `self` with an additional prop. The prop is protected if its name starts with `'!'`. If `self`'s
+    /// props are already redacted, e.g., because `self` was deserialized, a protected prop's value is redacted
+    /// as per the current [`redaction_policy`](super::redaction_policy).
+    pub fn with_prop(mut self, name: &str, value: impl Into<PropValue>) -> Self {
+        self.props
+            .push(name, value.into(), &super::redaction_policy());
+        self
+    }
+
+    /// Returns `self` with `note` added to its breadcrumbs.
+    pub fn with_breadcrumb(mut self, note: impl Into<String>) -> Self {
+        self.meta.trail.breadcrumbs.push(note.into());
+        self
+    }
+}
+
+impl Error {
+    /// Returns a new error of kind `kind` with `self` as its source. The new error takes over `self`'s
+    /// [`Trail`], with `self`'s kind appended to the kind history, so that the trail is not repeated in the
+    /// source chain.
+    #[track_caller]
+    pub fn wrap_in<SRC>(mut self, kind: &'static BasicKind<SRC>) -> Error
+    where
+        SRC: SendSyncStaticError + From<Error>,
+    {
+        let trail = self.take_trail();
+        let mut err = kind.error_with_src(self.into());
+        err.meta.trail = trail;
+        err
+    }
+
+    /// Same as [`Self::wrap_in`] but with prop values for the new error.
+    #[track_caller]
+    pub fn wrap_in_with_values<const ARITY: usize, SRC>(
+        mut self,
+        kind: &'static PropsKind<ARITY, SRC>,
+        values: [impl Into<PropValue>; ARITY],
+    ) -> Error
+    where
+        SRC: SendSyncStaticError + From<Error>,
+    {
+        let trail = self.take_trail();
+        let mut err = kind.error_with_values_src(values, self.into());
+        err.meta.trail = trail;
+        err
+    }
+
+    fn take_trail(&mut self) -> Trail {
+        let mut trail = mem::take(&mut self.meta.trail);
+        trail.kind_history.push(self.kind_id);
+        trail
+    }
+}
+
+// endregion:   --- Error context methods
+
+//===========================
+// region:      --- ResultExt
+
+/// Extension methods to enrich the error of a [`Result`] with context as it propagates.
+pub trait ResultExt<T> {
+    /// See [`Error::with_prop`].
+    fn with_prop(self, name: &str, value: impl Into<PropValue>) -> Result<T>;
+
+    /// See [`Error::with_breadcrumb`].
+    fn with_breadcrumb(self, note: impl Into<String>) -> Result<T>;
+
+    /// Same as [`Self::with_breadcrumb`] but the note is only computed in case of error.
+    fn with_breadcrumb_lazy(self, f: impl FnOnce() -> String) -> Result<T>;
+
+    /// See [`Error::wrap_in`].
+    fn wrap_in<SRC>(self, kind: &'static BasicKind<SRC>) -> Result<T>
+    where
+        SRC: SendSyncStaticError + From<Error>;
+
+    /// See [`Error::wrap_in_with_values`].
+    fn wrap_in_with_values<const ARITY: usize, SRC>(
+        self,
+        kind: &'static PropsKind<ARITY, SRC>,
+        values: [impl Into<PropValue>; ARITY],
+    ) -> Result<T>
+    where
+        SRC: SendSyncStaticError + From<Error>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn with_prop(self, name: &str, value: impl Into<PropValue>) -> Result<T> {
+        self.map_err(|err| err.with_prop(name, value))
+    }
+
+    fn with_breadcrumb(self, note: impl Into<String>) -> Result<T> {
+        self.map_err(|err| err.with_breadcrumb(note))
+    }
+
+    fn with_breadcrumb_lazy(self, f: impl FnOnce() -> String) -> Result<T> {
+        self.map_err(|err| err.with_breadcrumb(f()))
+    }
+
+    #[track_caller]
+    fn wrap_in<SRC>(self, kind: &'static BasicKind<SRC>) -> Result<T>
+    where
+        SRC: SendSyncStaticError + From<Error>,
+    {
+        // Not `map_err`, so that the new error's location is the caller's.
+        match self {
+            Ok(v) => Ok(v),
+            Err(err) => Err(err.wrap_in(kind)),
+        }
+    }
+
+    #[track_caller]
+    fn wrap_in_with_values<const ARITY: usize, SRC>(
+        self,
+        kind: &'static PropsKind<ARITY, SRC>,
+        values: [impl Into<PropValue>; ARITY],
+    ) -> Result<T>
+    where
+        SRC: SendSyncStaticError + From<Error>,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(err) => Err(err.wrap_in_with_values(kind, values)),
+        }
+    }
+}
+
+// endregion:   --- ResultExt
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{DeserError, Fmt, StringSpec, Tag, TransmuterKind};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static APP_ERROR: BasicKind<Error> = BasicKind::new("TRAIL_APP_ERROR", None, &FOO_TAG);
+
+    static DAF_ERROR: BasicKind = BasicKind::new("TRAIL_DAF_ERROR", None, &FOO_TAG);
+
+    static SFL_ERROR: PropsKind<1, Error> = PropsKind::new_with_props(
+        "TRAIL_SFL_ERROR",
+        Some("sfl failed for {id}"),
+        &FOO_TAG,
+        ["id"],
+    );
+
+    static NOT_FOUND_ERROR: TransmuterKind =
+        TransmuterKind::new("TRAIL_NOT_FOUND_ERROR", Some("not found"), &FOO_TAG);
+
+    fn read_daf() -> Result<()> {
+        Err(DAF_ERROR.error()).with_breadcrumb("reading foo")
+    }
+
+    fn foo_sfl() -> Result<()> {
+        read_daf()
+            .with_prop("table", "foo")
+            .wrap_in_with_values(&SFL_ERROR, [42])
+            .with_breadcrumb_lazy(|| "in foo_sfl".to_owned())
+    }
+
+    #[test]
+    fn test_trail() {
+        let err = foo_sfl().wrap_in(&APP_ERROR).unwrap_err();
+        assert!(err.has_kind(APP_ERROR.kind_id()));
+        assert_eq!(
+            err.trail().kind_history(),
+            [DAF_ERROR.kind_id(), SFL_ERROR.kind_id()]
+        );
+        assert_eq!(err.trail().breadcrumbs(), ["reading foo", "in foo_sfl"]);
+
+        let src = err.downcast_src_ref::<Error>().unwrap();
+        assert_eq!(src.to_string(), "sfl failed for 42");
+        assert!(src.trail().is_empty());
+        let daf_err = src.downcast_src_ref::<Error>().unwrap();
+        assert_eq!(daf_err.props().prop_str("table"), Some("foo"));
+        assert!(daf_err.trail().is_empty());
+
+        assert_eq!(
+            Fmt(&err).speced_string(&StringSpec::Trail),
+            "kind_history=[TRAIL_DAF_ERROR, TRAIL_SFL_ERROR], breadcrumbs=[reading foo, in foo_sfl]"
+        );
+
+        let ser_err = err.to_sererror_no_payload_src([]);
+        let json = serde_json::to_value(&ser_err).unwrap();
+        assert_eq!(
+            json["trail"],
+            serde_json::json!({
+                "kind_history": ["TRAIL_DAF_ERROR", "TRAIL_SFL_ERROR"],
+                "breadcrumbs": ["reading foo", "in foo_sfl"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_transmute_history() {
+        let err = NOT_FOUND_ERROR.transmute(DAF_ERROR.error().with_breadcrumb("reading foo"));
+        assert!(err.has_kind(NOT_FOUND_ERROR.kind_id()));
+        assert!(!err.has_kind(DAF_ERROR.kind_id()));
+        assert!(err.was_kind(NOT_FOUND_ERROR.kind_id()));
+        assert!(err.was_kind(DAF_ERROR.kind_id()));
+        assert!(!err.was_kind(SFL_ERROR.kind_id()));
+        assert!(format!("{err:?}").contains("kind_history: [KindId(TRAIL_DAF_ERROR"));
+
+        let ser_err = err.to_sererror_no_payload_src([]);
+        assert!(ser_err.was_kind(DAF_ERROR.kind_id()));
+        let json = serde_json::to_string(&ser_err).unwrap();
+        let deser_err: DeserError = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deser_err.trail.kind_history,
+            [DeserKindId("TRAIL_DAF_ERROR".into())]
+        );
+    }
+}
@@ -1,6 +1,12 @@
 use rand::random;
-use std::{backtrace::Backtrace, error::Error as StdError};
+use std::{
+    backtrace::Backtrace,
+    error::Error as StdError,
+    panic::Location,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
+use super::{ser_source_chain, FrameFilter, Trail, DEFAULT_FRAME_FILTER};
 use crate::string::{self, hex_lower_of_u8_arr};
 
 // ===========================
@@ -73,6 +79,32 @@ pub fn ref_id_u32_hex_lower() -> String {
     hex_lower_of_u8_arr(&rnd)
 }
 
+/// Returns `time` in RFC 3339 format, in UTC with millisecond precision, e.g., `2024-05-17T09:41:07.042Z`.
+pub fn rfc3339_utc(time: SystemTime) -> String {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0);
+    let (days, ms_of_day) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+    // Civil date from days since the Unix epoch, as per http://howardhinnant.github.io/date_algorithms.html.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000
+    )
+}
+
 // endregion:   --- General utilities
 
 // ===========================
@@ -85,6 +117,20 @@ pub enum StringSpec<'a> {
     SourceDbg,
     Backtrace,
     BacktraceDbg,
+    /// Backtrace with frames filtered by the given filter (see [`FrameFilter::render`]).
+    FilteredBacktrace(&'a FrameFilter<'a>),
+    /// Backtrace frames filtered by the given filter, as a JSON array (see [`FrameFilter::render_json`]).
+    BacktraceJson(&'a FrameFilter<'a>),
+    Trail,
+    /// Source location where the error was created, as `file:line:column`.
+    /// [`SerError`](super::SerError)s include it as a separate field instead.
+    Location,
+    /// Time when the error was created, in RFC 3339 format.
+    /// [`SerError`](super::SerError)s include it as a separate field instead.
+    CreatedAt,
+    /// Errors in the source chain, as a JSON array of [`SerSource`](super::SerSource)s (see [`ser_source_chain`]).
+    /// [`SerError`](super::SerError)s include them as a structured array instead.
+    SourceChain,
     Decor(&'a Self, Option<&'a str>, Option<&'a str>),
 }
 
@@ -95,6 +141,21 @@ pub enum StringSpec<'a> {
 
 pub trait WithBacktrace {
     fn backtrace(&self) -> &Backtrace;
+
+    /// Returns the error's [`Trail`], if it keeps one.
+    fn trail(&self) -> Option<&Trail> {
+        None
+    }
+
+    /// Returns the source location where the error was created, if it keeps one.
+    fn location(&self) -> Option<&'static Location<'static>> {
+        None
+    }
+
+    /// Returns the time when the error was created, if it keeps one.
+    fn created_at(&self) -> Option<SystemTime> {
+        None
+    }
 }
 
 pub struct Fmt<'a, T: StdError + WithBacktrace>(pub &'a T);
@@ -120,6 +181,43 @@ impl<'a, T: StdError + WithBacktrace> Fmt<'a, T> {
         format!("{:?}", self.0.backtrace())
     }
 
+    /// Backtrace filtered with [`DEFAULT_FRAME_FILTER`].
+    pub fn filtered_backtrace_string(&self) -> String {
+        DEFAULT_FRAME_FILTER.render(self.0.backtrace())
+    }
+
+    /// Backtrace frames filtered with [`DEFAULT_FRAME_FILTER`], as a JSON array.
+    pub fn backtrace_json(&self) -> String {
+        DEFAULT_FRAME_FILTER.render_json(self.0.backtrace())
+    }
+
+    pub fn trail_string(&self) -> String {
+        match self.0.trail() {
+            Some(trail) => trail.to_string(),
+            None => String::new(),
+        }
+    }
+
+    pub fn location_string(&self) -> String {
+        match self.0.location() {
+            Some(location) => location.to_string(),
+            None => String::new(),
+        }
+    }
+
+    pub fn created_at_string(&self) -> String {
+        match self.0.created_at() {
+            Some(created_at) => rfc3339_utc(created_at),
+            None => String::new(),
+        }
+    }
+
+    /// Errors in the source chain, as a JSON array of [`SerSource`](super::SerSource)s.
+    pub fn source_chain_json(&self) -> String {
+        serde_json::to_string(&ser_source_chain(self.0))
+            .expect("serialization of source chain can't fail")
+    }
+
     pub fn speced_string(&self, str_spec: &StringSpec) -> String {
         match str_spec {
             StringSpec::Dbg => self.dbg_string(),
@@ -127,6 +225,12 @@ impl<'a, T: StdError + WithBacktrace> Fmt<'a, T> {
             StringSpec::SourceDbg => self.source_dbg_string(),
             StringSpec::Backtrace => self.backtrace_string(),
             StringSpec::BacktraceDbg => self.backtrace_dbg_string(),
+            StringSpec::FilteredBacktrace(filter) => filter.render(self.0.backtrace()),
+            StringSpec::BacktraceJson(filter) => filter.render_json(self.0.backtrace()),
+            StringSpec::Trail => self.trail_string(),
+            StringSpec::Location => self.location_string(),
+            StringSpec::CreatedAt => self.created_at_string(),
+            StringSpec::SourceChain => self.source_chain_json(),
             StringSpec::Decor(&ref spec, pre, post) => {
                 string::decorated(&self.speced_string(spec), *pre, *post)
             }
@@ -148,6 +252,12 @@ impl<'a, T: StdError + WithBacktrace> Fmt<'a, T> {
             ("source_dbg_string", Self::source_dbg_string),
             ("backtrace_string", Self::backtrace_string),
             ("backtrace_dbg_string", Self::backtrace_dbg_string),
+            ("filtered_backtrace_string", Self::filtered_backtrace_string),
+            ("backtrace_json", Self::backtrace_json),
+            ("trail_string", Self::trail_string),
+            ("location_string", Self::location_string),
+            ("created_at_string", Self::created_at_string),
+            ("source_chain_json", Self::source_chain_json),
         ];
         string::interpolated_props_lazy(fmt, props.into_iter(), self)
     }
@@ -159,6 +269,17 @@ impl<'a, T: StdError + WithBacktrace> Fmt<'a, T> {
             StringSpec::SourceDbg => ("source_dbg_string", self.source_dbg_string()),
             StringSpec::Backtrace => ("backtrace_string", self.backtrace_string()),
             StringSpec::BacktraceDbg => ("backtrace_dbg_string", self.backtrace_dbg_string()),
+            StringSpec::FilteredBacktrace(filter) => (
+                "filtered_backtrace_string",
+                filter.render(self.0.backtrace()),
+            ),
+            StringSpec::BacktraceJson(filter) => {
+                ("backtrace_json", filter.render_json(self.0.backtrace()))
+            }
+            StringSpec::Trail => ("trail_string", self.trail_string()),
+            StringSpec::Location => ("location_string", self.location_string()),
+            StringSpec::CreatedAt => ("created_at_string", self.created_at_string()),
+            StringSpec::SourceChain => ("source_chain_json", self.source_chain_json()),
             StringSpec::Decor(&ref spec, _, _) => self.speced_string_tuple(spec),
         }
     }
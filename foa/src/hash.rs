@@ -1,3 +1,4 @@
+use hmac::{Hmac, Mac};
 use sha2::{Digest, Sha256};
 
 /// Computes the MD5 hash of a string array.
@@ -33,3 +34,20 @@ where
     }
     hasher.finalize().into()
 }
+
+/// Computes the HMAC-SHA256 of a string array with a given key.
+/// When computing the HMAC, elements of the array are interspersed with 0 bytes to differentiate,
+/// for example, between ["hello", "world"] and ["helloworld"].
+pub fn hmac_sha256_of_str_arr<T>(key: &[u8], arr: &[T]) -> [u8; 32]
+where
+    T: AsRef<str>,
+{
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for (i, data) in arr.iter().enumerate() {
+        if i > 0 {
+            mac.update(&[0_u8; 1]);
+        }
+        mac.update(data.as_ref().as_bytes());
+    }
+    mac.finalize().into_bytes().into()
+}
@@ -1,5 +1,6 @@
 use crate::context::{ErrCtx, Locale, LocalizedMsg};
 use base64ct::{Base64, Encoding};
+use std::iter;
 
 /// Interpolates a string with a list of arguments.
 pub fn interpolated_vec<S>(mut raw_msg: &str, args: &[S]) -> String
@@ -56,6 +57,106 @@ where
     msg
 }
 
+/// Returns the names of the `{name}` placeholders in a string, in order of appearance.
+/// Positional `{}` placeholders are ignored.
+pub fn placeholder_names(mut raw_msg: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    while let Some(start) = raw_msg.find('{') {
+        raw_msg = &raw_msg[start + 1..];
+        let Some(end) = raw_msg.find('}') else {
+            break;
+        };
+        let name = &raw_msg[..end];
+        if !name.is_empty() {
+            names.push(name);
+        }
+        raw_msg = &raw_msg[end + 1..];
+    }
+    names
+}
+
+/// Returns the byte range of the name of the first `{name}` placeholder in `raw_msg` at or after byte `from`,
+/// with the same semantics as [`placeholder_names`], except that empty names are included.
+/// Usable in const contexts.
+const fn next_placeholder(raw_msg: &[u8], from: usize) -> Option<(usize, usize)> {
+    let mut start = from;
+    while start < raw_msg.len() && raw_msg[start] != b'{' {
+        start += 1;
+    }
+    let mut end = start + 1;
+    while end < raw_msg.len() && raw_msg[end] != b'}' {
+        end += 1;
+    }
+    if end >= raw_msg.len() {
+        return None;
+    }
+    Some((start + 1, end))
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn contains_name(names: &[&str], name: &[u8]) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        if bytes_eq(names[i].as_bytes(), name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns whether every `{name}` placeholder in `raw_msg` (see [`placeholder_names`]) is in `names`.
+/// Usable in const contexts.
+pub const fn placeholders_declared(raw_msg: &str, names: &[&str]) -> bool {
+    let bytes = raw_msg.as_bytes();
+    let mut from = 0;
+    while let Some((start, end)) = next_placeholder(bytes, from) {
+        let name = bytes.split_at(end).0.split_at(start).1;
+        if !name.is_empty() && !contains_name(names, name) {
+            return false;
+        }
+        from = end + 1;
+    }
+    true
+}
+
+/// Returns whether every element of `names` appears as a `{name}` placeholder in `raw_msg`
+/// (see [`placeholder_names`]). Usable in const contexts.
+pub const fn names_used(raw_msg: &str, names: &[&str]) -> bool {
+    let bytes = raw_msg.as_bytes();
+    let mut i = 0;
+    while i < names.len() {
+        let mut found = false;
+        let mut from = 0;
+        while let Some((start, end)) = next_placeholder(bytes, from) {
+            let name = bytes.split_at(end).0.split_at(start).1;
+            if bytes_eq(names[i].as_bytes(), name) {
+                found = true;
+                break;
+            }
+            from = end + 1;
+        }
+        if !found {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 /// Lazily interpolates a string with properties (list of name-value pairs),
 /// where the values are returned by functions from a common input.
 pub fn interpolated_props_lazy<'a, P, S1, S2, T>(raw_msg: &'a str, props: P, input: &T) -> String
@@ -96,6 +197,17 @@ where
     CTX::LocalizedMsg::localized_msg(kind, CTX::Locale::locale())
 }
 
+/// Returns a locale (language tag) followed by its progressively less specific prefixes, e.g.,
+/// `zh-Hant-TW` -> `zh-Hant` -> `zh`. Returns an empty iterator if `locale` is empty.
+pub fn locale_fallbacks(locale: &str) -> impl Iterator<Item = &str> {
+    let mut next = Some(locale).filter(|locale| !locale.is_empty());
+    iter::from_fn(move || {
+        let curr = next?;
+        next = curr.rfind('-').map(|idx| &curr[..idx]);
+        Some(curr)
+    })
+}
+
 /// Encodes a byte array as a lower hex string.
 pub fn hex_lower_of_u8_arr(arr: &[u8]) -> String {
     arr.iter().map(|b| format!("{:02x}", b)).collect::<String>()
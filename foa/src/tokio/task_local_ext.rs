@@ -1,11 +1,29 @@
 use super::task_local::TaskLocal;
-use crate::context::LocaleSelf;
+use crate::context::{LocaleSelf, RequestIdSelf};
 use std::ops::Deref;
 
-pub fn locale_from_task_local<T>(default: impl Deref<Target = str>) -> impl Deref<Target = str>
+/// Returns the element of `supported` that best matches the locale preferences of the task-local value
+/// (see [`LocaleSelf::supported_locale`]), or `default` if there is no match.
+pub fn locale_from_task_local<T>(
+    supported: &[&str],
+    default: impl Deref<Target = str>,
+) -> impl Deref<Target = str>
 where
     T: TaskLocal,
     T::Value: LocaleSelf,
 {
-    T::with(|v| v.locale().unwrap_or_else(|| &default).to_owned())
+    T::with(|v| v.supported_locale(supported).unwrap_or(&default).to_owned())
+}
+
+/// Returns the request id of the task-local value (see [`RequestIdSelf`]), or `None` if there is none or
+/// the task-local is not set. Can be used as a [`RefIdGen::Custom`](crate::error::RefIdGen::Custom)
+/// generator, e.g., `RefIdGen::Custom(ref_id_from_task_local::<MyTaskLocal>)`.
+pub fn ref_id_from_task_local<T>() -> Option<String>
+where
+    T: TaskLocal,
+    T::Value: RequestIdSelf,
+{
+    T::try_with(|v| v.request_id().map(str::to_owned))
+        .ok()
+        .flatten()
 }
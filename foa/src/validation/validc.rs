@@ -8,6 +8,7 @@ pub static VALIDATION_ERROR: PayloadKind<ValidationError> =
     PayloadKind::new_with_payload("VALIDATION_ERROR", None, &VALIDATION_TAG);
 
 impl From<ValidationError> for Error {
+    #[track_caller]
     fn from(value: ValidationError) -> Self {
         VALIDATION_ERROR.error_with_payload(value)
     }
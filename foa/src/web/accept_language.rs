@@ -0,0 +1,4 @@
+//! Parsing of the HTTP `Accept-Language` header and locale negotiation, re-exported from
+//! [`crate::context`] for use by web handlers.
+
+pub use crate::context::{negotiate_locale, parse_accept_language, LanguageRange};
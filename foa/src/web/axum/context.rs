@@ -1,9 +1,122 @@
-use crate::context::LocaleSelf;
-use axum::http::request::Parts;
+use crate::{
+    context::{LocaleSelf, RequestIdSelf},
+    web::{negotiate_locale, parse_accept_language},
+};
+use axum::{
+    http::{header, request::Parts, HeaderValue},
+    response::{IntoResponseParts, ResponseParts},
+};
+use std::convert::Infallible;
 
-// TODO: needs better implementation that parses the header appropriately
 impl LocaleSelf for Parts {
+    /// Returns the most preferred non-wildcard language range in the `Accept-Language` header.
     fn locale(&self) -> Option<&str> {
-        self.headers.get("Accept-Language")?.to_str().ok()
+        let header = self.headers.get(header::ACCEPT_LANGUAGE)?.to_str().ok()?;
+        parse_accept_language(header)
+            .into_iter()
+            .find(|r| r.q > 0.0 && !r.is_wildcard())
+            .map(|r| r.range)
+    }
+
+    /// Returns the element of `supported` that best matches the `Accept-Language` header as per
+    /// [`negotiate_locale`].
+    fn supported_locale<'a>(&self, supported: &[&'a str]) -> Option<&'a str> {
+        let header = self.headers.get(header::ACCEPT_LANGUAGE)?.to_str().ok()?;
+        negotiate_locale(header, supported)
+    }
+}
+
+/// Name of the request header that holds the request id (see [`RequestIdSelf`]).
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Maximum length of request ids accepted from the [`REQUEST_ID_HEADER`] header.
+pub const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// Whether `id` is non-empty, at most [`MAX_REQUEST_ID_LEN`] long, and made only of ASCII alphanumerics,
+/// `'.'`, `'_'`, and `'-'`, so that it is safe to use as an error ref id and to write to logs.
+pub fn is_valid_request_id(id: &str) -> bool {
+    (1..=MAX_REQUEST_ID_LEN).contains(&id.len())
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
+
+impl RequestIdSelf for Parts {
+    /// Returns the value of the [`REQUEST_ID_HEADER`] header, or `None` if it is not a valid request id as per
+    /// [`is_valid_request_id`], so that the ref id generator is used instead.
+    fn request_id(&self) -> Option<&str> {
+        let id = self.headers.get(REQUEST_ID_HEADER)?.to_str().ok()?;
+        is_valid_request_id(id).then_some(id)
+    }
+}
+
+/// Response part that sets the `Content-Language` header to the wrapped locale, e.g., to the locale
+/// negotiated with [`LocaleSelf::supported_locale`]. The header is not set if the locale is not a
+/// valid header value.
+#[derive(Debug, Clone)]
+pub struct ContentLanguage<T: AsRef<str>>(pub T);
+
+impl<T: AsRef<str>> IntoResponseParts for ContentLanguage<T> {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if let Ok(value) = HeaderValue::from_str(self.0.as_ref()) {
+            res.headers_mut().insert(header::CONTENT_LANGUAGE, value);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use axum::{http::Request, response::IntoResponse};
+
+    fn make_parts(accept_language: &str) -> Parts {
+        let req = Request::builder()
+            .header(header::ACCEPT_LANGUAGE, accept_language)
+            .body(())
+            .unwrap();
+        req.into_parts().0
+    }
+
+    #[test]
+    fn test_locale_self() {
+        let parts = make_parts("*;q=0.9, fr-CA;q=0.5, pt-BR");
+        assert_eq!(parts.locale(), Some("pt-BR"));
+        assert_eq!(parts.supported_locale(&["en", "fr"]), Some("en"));
+        assert_eq!(parts.supported_locale(&["fr", "pt"]), Some("pt"));
+    }
+
+    #[test]
+    fn test_request_id_self() {
+        let parts = make_parts("en");
+        assert_eq!(parts.request_id(), None);
+        let req = Request::builder()
+            .header(REQUEST_ID_HEADER, "req-42")
+            .body(())
+            .unwrap();
+        assert_eq!(req.into_parts().0.request_id(), Some("req-42"));
+
+        let req = Request::builder()
+            .header(REQUEST_ID_HEADER, "req 42")
+            .body(())
+            .unwrap();
+        assert_eq!(req.into_parts().0.request_id(), None);
+
+        assert!(is_valid_request_id("0af7651916cd43dd.8448eb211c80319c_1-2"));
+        assert!(is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LEN)));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LEN + 1)));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("req\r\nfoo"));
+        assert!(!is_valid_request_id("req/42"));
+    }
+
+    #[test]
+    fn test_content_language() {
+        let parts = make_parts("fr-CA, en;q=0.5");
+        let locale = parts.supported_locale(&["en", "fr"]).unwrap();
+        let res = (ContentLanguage(locale), "body").into_response();
+        assert_eq!(res.headers().get(header::CONTENT_LANGUAGE).unwrap(), "fr");
     }
 }
@@ -232,3 +232,62 @@ where
         h.call(req, state)
     }
 }
+
+/// Type wrapper for an `AsyncFn2<Out = Result<O, E>>` and a function that maps errors to a value `EMR` that
+/// implements [`IntoResponse`]; implements a handler that takes [`Json<F::In2>`] as the second argument and
+/// returns [`Result<Json<O>, EMR>`]. Unlike with [`HandlerAsyncFn2rsWithErrorMapper`], the response for errors
+/// is rendered by `EMR`, e.g., as a body of media type `application/problem+json` with
+/// [`problem_details_mapper`](crate::web::problem_details_mapper).
+pub struct HandlerAsyncFn2rsWithErrorResponseMapper<EMI, EMR, F, M>(F, M, PhantomData<(EMI, EMR)>);
+
+impl<O, E, EMI, EMR, F, M> HandlerAsyncFn2rsWithErrorResponseMapper<EMI, EMR, F, M>
+where
+    F: AsyncFn2<Out = Result<O, E>> + Send + Sync + 'static + Clone,
+    F::In2: DeserializeOwned,
+    O: Serialize + Send,
+    E: Into<EMI> + Send,
+    M: Fn(EMI) -> EMR + Send + Sync + 'static + Clone,
+    EMI: Send + 'static,
+    EMR: IntoResponse + Send + 'static,
+{
+    pub fn new(f: F, m: M) -> Self {
+        Self(f, m, PhantomData)
+    }
+}
+
+impl<EMI, EMR, F, M> Clone for HandlerAsyncFn2rsWithErrorResponseMapper<EMI, EMR, F, M>
+where
+    F: Clone,
+    M: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), self.1.clone(), PhantomData)
+    }
+}
+
+impl<O, E, EMI, EMR, F, M, S> Handler<(), S>
+    for HandlerAsyncFn2rsWithErrorResponseMapper<EMI, EMR, F, M>
+where
+    F: AsyncFn2<Out = Result<O, E>> + Send + Sync + 'static + Clone,
+    F::In1: FromRequestParts<S>,
+    F::In2: DeserializeOwned,
+    O: Serialize + Send,
+    E: Into<EMI> + Send,
+    S: Send + Sync + 'static,
+    M: Fn(EMI) -> EMR + Send + Sync + 'static + Clone,
+    EMI: Send + 'static,
+    EMR: IntoResponse + Send + 'static,
+{
+    type Future = Pin<Box<dyn Future<Output = Response> + Send>>;
+
+    fn call(self, req: Request, state: S) -> Self::Future {
+        let Self(f, m, _) = self;
+        let h = move |in1: F::In1, Json(in2): Json<F::In2>| async move {
+            match f.invoke(in1, in2).await {
+                Ok(out) => Ok(Json(out)),
+                Err(err) => Err(m(err.into())),
+            }
+        };
+        h.call(req, state)
+    }
+}
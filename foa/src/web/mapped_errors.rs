@@ -1,5 +1,6 @@
 use crate::{
-    error::{self, Error, JserBoxError, VALIDATION_TAG},
+    context::{ErrCtx, NullCtx},
+    error::{self, Error, JserBoxError, MultiError, VALIDATION_TAG},
     fun::AsyncFn2,
 };
 use http::StatusCode;
@@ -46,8 +47,9 @@ where
     }
 }
 
-fn error_string_for_error_level(err: &Error) -> String {
-    err.as_fmt().multi_speced_string([
+pub(super) fn error_string_for_error_level(err: &Error) -> String {
+    let ref_id = err.ref_id().unwrap_or("-");
+    let txt = err.as_fmt().multi_speced_string([
         error::StringSpec::Dbg,
         error::StringSpec::Decor(
             &error::StringSpec::Recursive,
@@ -56,22 +58,74 @@ fn error_string_for_error_level(err: &Error) -> String {
         ),
         error::StringSpec::Decor(&error::StringSpec::SourceDbg, Some("source="), None),
         error::StringSpec::Decor(&error::StringSpec::Backtrace, Some("backtrace=\n"), None),
-    ])
+    ]);
+    format!("ref_id={ref_id}, {txt}")
 }
 
+/// Maps an [`Error`] to a status code and a serializable error with the error's default message.
+/// The serializable error includes the error's ref id, if any, which is also included in the logs
+/// of server errors. See [`super::StatusMapper`] for a configurable alternative.
 pub fn default_mapper(err: Error) -> (StatusCode, JserBoxError) {
+    localized_mapper::<NullCtx>(err)
+}
+
+/// Returns the status code for `err` used by [`default_mapper`] and [`localized_mapper`]: 400 for errors
+/// whose tag is a [`VALIDATION_TAG`] (see [`error::Tag::is_a`]) and 500 otherwise. For
+/// [`error::MULTI_ERROR`]s, it is the most severe (highest) status code of the children.
+pub fn default_status_code(err: &Error) -> StatusCode {
+    match err.downcast_payload_ref::<MultiError>() {
+        Some(multi) => multi
+            .children()
+            .iter()
+            .map(|child| default_status_code(&child.error))
+            .max()
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        None if err.tag().is_a(&VALIDATION_TAG) => StatusCode::BAD_REQUEST,
+        None => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Maps an [`Error`] to a status code and a serializable error whose message is localized for `CTX`
+/// (see [`error::SerError::localized`]). `CTX`'s locale must be available where the mapper runs.
+///
+/// An [`error::MULTI_ERROR`] is mapped with its children as a nested array payload and the status code
+/// given by [`default_status_code`].
+pub fn localized_mapper<CTX: ErrCtx>(err: Error) -> (StatusCode, JserBoxError) {
+    if err.payload_is::<MultiError>() {
+        let status_code = default_status_code(&err);
+        if status_code.is_server_error() {
+            log!(Level::Error, "{}", error_string_for_error_level(&err));
+        }
+        let ser_err = err
+            .force_downcast_payload::<MultiError>()
+            .into_sererror_with_payload([])
+            .localized::<CTX>()
+            .map_payload(|multi| {
+                multi
+                    .to_ser_children()
+                    .into_iter()
+                    .map(|child| child.localized::<CTX>())
+                    .collect::<Vec<_>>()
+            });
+        return (status_code, ser_err.into());
+    }
+
     match err.tag() {
-        tag if tag == &VALIDATION_TAG => {
+        tag if tag.is_a(&VALIDATION_TAG) => {
             let status_code = StatusCode::BAD_REQUEST;
             let err_exp_res = err.downcast_payload::<ValidationError>();
             match err_exp_res {
-                Ok(ee) => (status_code, ee.into_sererror_with_payload([]).into()),
+                Ok(ee) => (
+                    status_code,
+                    ee.into_sererror_with_payload([]).localized::<CTX>().into(),
+                ),
                 Err(e) => (
                     status_code,
                     e.to_sererror_no_payload_src([
                         error::StringSpec::Dbg,
                         error::StringSpec::Recursive,
                     ])
+                    .localized::<CTX>()
                     .into(),
                 ),
             }
@@ -84,6 +138,7 @@ pub fn default_mapper(err: Error) -> (StatusCode, JserBoxError) {
                     error::StringSpec::Dbg,
                     error::StringSpec::Recursive,
                 ])
+                .localized::<CTX>()
                 .into(),
             )
         }
@@ -94,7 +149,7 @@ pub fn default_mapper(err: Error) -> (StatusCode, JserBoxError) {
 /// For exploratory purpuses
 pub fn default_mapper1(err: Error) -> (StatusCode, JserBoxError) {
     match err.tag() {
-        tag if tag == &VALIDATION_TAG => {
+        tag if tag.is_a(&VALIDATION_TAG) => {
             let status_code = StatusCode::BAD_REQUEST;
             err.chained_map(
                 |e| {
@@ -127,3 +182,64 @@ pub fn default_mapper1(err: Error) -> (StatusCode, JserBoxError) {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BasicKind, PayloadKind, Tag};
+    use valid::{constraint::Bound, Validate};
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static FIELD_TAG: Tag = Tag::new("MAPPER_FIELD").with_parent(&VALIDATION_TAG);
+
+    static FIELD_ERROR: PayloadKind<ValidationError> =
+        PayloadKind::new_with_payload("MAPPER_FIELD_ERROR", None, &FIELD_TAG);
+
+    static CHECK_ERROR: BasicKind = BasicKind::new("MAPPER_CHECK_ERROR", None, &VALIDATION_TAG);
+
+    static FOO_ERROR: BasicKind = BasicKind::new("MAPPER_FOO_ERROR", None, &FOO_TAG);
+
+    #[test]
+    fn test_multi_error_mapping() {
+        let mut multi = MultiError::new();
+        multi.push_at("a", CHECK_ERROR.error());
+        multi.push_at("b", CHECK_ERROR.error());
+        let (status_code, body) = default_mapper(multi.into_error());
+        assert_eq!(status_code, StatusCode::BAD_REQUEST);
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["kind_id"], "MULTI_ERROR");
+        assert_eq!(json["payload"][1]["path"], "b");
+        assert!(json.get("location").is_none());
+        assert!(json.get("created_at").is_none());
+
+        let mut inner = MultiError::new();
+        inner.push(FOO_ERROR.error());
+        let multi = [CHECK_ERROR.error(), inner.into_error()]
+            .into_iter()
+            .collect::<MultiError>();
+        let (status_code, body) = default_mapper(multi.into_error());
+        assert_eq!(status_code, StatusCode::INTERNAL_SERVER_ERROR);
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(
+            json["payload"][1]["errors"][0]["kind_id"],
+            "MAPPER_FOO_ERROR"
+        );
+    }
+
+    #[test]
+    fn test_validation_child_tag_mapping() {
+        let payload = (-1)
+            .validate("age must be nonnegative", &Bound::ClosedRange(0, i32::MAX))
+            .result()
+            .expect_err("validation designed to fail");
+        let err = FIELD_ERROR.error_with_payload(payload);
+        assert_eq!(default_status_code(&err), StatusCode::BAD_REQUEST);
+
+        let (status_code, body) = default_mapper(err);
+        assert_eq!(status_code, StatusCode::BAD_REQUEST);
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["kind_id"], "MAPPER_FIELD_ERROR");
+        assert!(!json["payload"].is_null());
+    }
+}
@@ -1,5 +1,11 @@
+mod accept_language;
 mod mapped_errors;
+mod problem_details;
+mod status_mapper;
 
 pub mod axum;
 
+pub use accept_language::*;
 pub use mapped_errors::*;
+pub use problem_details::*;
+pub use status_mapper::*;
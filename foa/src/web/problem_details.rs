@@ -0,0 +1,224 @@
+use super::{default_status_code, mapped_errors::error_string_for_error_level};
+use crate::{
+    context::{ErrCtx, NullCtx},
+    error::{Error, MultiError},
+    string,
+};
+use axum::response::{IntoResponse, Response};
+use http::{header, HeaderValue, StatusCode};
+use log::{log, Level};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Media type of [`ProblemDetails`] responses.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Prefix of the `type` member of [`ProblemDetails`], which is followed by the error's kind id.
+pub const PROBLEM_TYPE_PREFIX: &str = "urn:foa:kind:";
+
+/// Names of the standard members of [`ProblemDetails`], which props can't override.
+const STANDARD_MEMBERS: [&str; 5] = ["type", "title", "status", "detail", "instance"];
+
+/// Names of the extension members set by [`ProblemDetails::from_error`], which props can't override either.
+const RESERVED_EXTENSIONS: [&str; 1] = ["errors"];
+
+/// Problem Details representation of an [`Error`], as per
+/// [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807).
+///
+/// - `type` is [`PROBLEM_TYPE_PREFIX`] followed by the kind id.
+/// - `title` is the (localized) kind message if it has no placeholders for the error's props, or the status
+///   code's canonical reason otherwise, so that it doesn't vary between occurrences.
+/// - `detail` is the (localized) message interpolated with the error's props.
+/// - `instance` is the error's ref id, if any.
+/// - The error's props, redacted as per the current [`RedactionPolicy`](crate::error::RedactionPolicy), are
+///   extension members, with the `'!'` prefix removed from the names of protected props. Props named as
+///   standard members or as `errors` are omitted.
+/// - For [`crate::error::MULTI_ERROR`]s, the children are the `errors` extension member.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+impl ProblemDetails {
+    /// Returns the Problem Details of `err` with status code `status` and messages localized for `CTX`.
+    pub fn from_error<CTX: ErrCtx>(err: &Error, status: StatusCode) -> Self {
+        let kind_id = err.kind_id().0;
+        let raw_msg = string::localized::<CTX>(kind_id).unwrap_or(err.msg());
+        let refs_props = err
+            .props()
+            .pairs()
+            .any(|(name, _)| string::names_used(raw_msg, &[name]));
+        let title = if refs_props {
+            status.canonical_reason().unwrap_or(raw_msg)
+        } else {
+            raw_msg
+        };
+        let detail = err.localized_msg::<CTX>();
+
+        let mut extensions = BTreeMap::new();
+        for (name, value) in err.props().safe_props().pairs() {
+            let name = name.trim_start_matches('!');
+            if !STANDARD_MEMBERS.contains(&name) && !RESERVED_EXTENSIONS.contains(&name) {
+                let value = serde_json::to_value(value).expect("prop values are serializable");
+                extensions.insert(name.to_owned(), value);
+            }
+        }
+        if let Some(multi) = err.downcast_payload_ref::<MultiError>() {
+            let children = multi
+                .to_ser_children()
+                .into_iter()
+                .map(|child| child.localized::<CTX>())
+                .collect::<Vec<_>>();
+            let children = serde_json::to_value(children).expect("child errors are serializable");
+            extensions.insert("errors".to_owned(), children);
+        }
+
+        Self {
+            type_uri: format!("{PROBLEM_TYPE_PREFIX}{kind_id}"),
+            title: title.to_owned(),
+            status: status.as_u16(),
+            detail: Some(detail),
+            instance: err.ref_id().map(str::to_owned),
+            extensions,
+        }
+    }
+}
+
+/// Responds with the status code and a JSON body of [`PROBLEM_JSON`] media type.
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut res = (status, axum::Json(self)).into_response();
+        res.headers_mut()
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        res
+    }
+}
+
+/// Alternative to [`super::default_mapper`] that maps an [`Error`] to a status code, as per
+/// [`default_status_code`], and its [`ProblemDetails`]. Server errors are logged.
+///
+/// Use with [`HandlerAsyncFn2rsWithErrorResponseMapper`](super::axum::HandlerAsyncFn2rsWithErrorResponseMapper)
+/// to respond with a body of media type [`PROBLEM_JSON`].
+pub fn problem_details_mapper(err: Error) -> (StatusCode, ProblemDetails) {
+    localized_problem_details_mapper::<NullCtx>(err)
+}
+
+/// Same as [`problem_details_mapper`] but with messages localized for `CTX`. `CTX`'s locale must be
+/// available where the mapper runs.
+pub fn localized_problem_details_mapper<CTX: ErrCtx>(err: Error) -> (StatusCode, ProblemDetails) {
+    let status_code = default_status_code(&err);
+    if status_code.is_server_error() {
+        log!(Level::Error, "{}", error_string_for_error_level(&err));
+    }
+    (
+        status_code,
+        ProblemDetails::from_error::<CTX>(&err, status_code),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        error::{set_redaction_policy, BasicKind, PropsKind, RedactionPolicy, Tag, VALIDATION_TAG},
+        fun::AsyncFn2,
+        web::axum::HandlerAsyncFn2rsWithErrorResponseMapper,
+    };
+    use axum::{body::Body, extract::Request, handler::Handler};
+    use serde_json::json;
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static AGE_ERROR: PropsKind<2> = PropsKind::new_with_props(
+        "PROBLEM_AGE_ERROR",
+        Some("age {age} is below {!min}"),
+        &VALIDATION_TAG,
+        ["age", "!min"],
+    );
+
+    static FOO_ERROR: BasicKind =
+        BasicKind::new("PROBLEM_FOO_ERROR", Some("foo failed"), &FOO_TAG).with_ref_id();
+
+    static BRACES_ERROR: BasicKind = BasicKind::new(
+        "PROBLEM_BRACES_ERROR",
+        Some("body must be {}"),
+        &VALIDATION_TAG,
+    );
+
+    #[test]
+    fn test_problem_details() {
+        // The protected `!min` prop would otherwise be masked in release builds.
+        set_redaction_policy(RedactionPolicy::Reveal);
+        let (status_code, problem) = problem_details_mapper(AGE_ERROR.error_with_values([15, 18]));
+        assert_eq!(status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            serde_json::to_value(&problem).unwrap(),
+            json!({
+                "type": "urn:foa:kind:PROBLEM_AGE_ERROR",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "age 15 is below 18",
+                "age": 15,
+                "min": 18,
+            })
+        );
+
+        let err = FOO_ERROR.error();
+        let ref_id = err.ref_id().unwrap().to_owned();
+        let (status_code, problem) = problem_details_mapper(err);
+        assert_eq!(status_code, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(problem.title, "foo failed");
+        assert_eq!(problem.instance, Some(ref_id));
+
+        let res = problem.into_response();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+
+        let (_, problem) = problem_details_mapper(BRACES_ERROR.error());
+        assert_eq!(problem.title, "body must be {}");
+
+        let err = BRACES_ERROR
+            .error()
+            .with_prop("errors", "not a child list")
+            .with_prop("detail", "not the detail");
+        let (_, problem) = problem_details_mapper(err);
+        assert_eq!(problem.detail.as_deref(), Some("body must be {}"));
+        assert!(problem.extensions.is_empty());
+    }
+
+    #[derive(Clone)]
+    struct FooFn;
+
+    impl AsyncFn2 for FooFn {
+        type In1 = ();
+        type In2 = ();
+        type Out = Result<(), Error>;
+
+        async fn invoke(&self, _: Self::In1, _: Self::In2) -> Self::Out {
+            Err(AGE_ERROR.error_with_values([15, 18]))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_problem_details_handler() {
+        let handler = HandlerAsyncFn2rsWithErrorResponseMapper::new(FooFn, problem_details_mapper);
+        let req = Request::builder()
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from("null"))
+            .unwrap();
+        let res = handler.call(req, ()).await;
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+    }
+}
@@ -0,0 +1,344 @@
+use super::mapped_errors::error_string_for_error_level;
+use crate::{
+    context::{ErrCtx, NullCtx},
+    error::{
+        Error, JserBoxError, KindId, MultiError, Payload, StringSpec, Tag, AUTH_TAG, CLIENT_TAG,
+        CONFLICT_TAG, FORBIDDEN_TAG, NOT_FOUND_TAG, RATE_LIMITED_TAG, TRANSIENT_TAG,
+        VALIDATION_TAG,
+    },
+};
+use http::StatusCode;
+use log::{log, Level};
+use serde::Serialize;
+use serde_json::Value;
+use std::{collections::HashMap, sync::Arc};
+use valid::ValidationError;
+
+//===========================
+// region:      --- StatusEntry
+
+/// Entry of a [`StatusMapper`]: the status code of the errors it applies to and what is exposed in the
+/// response body besides the error's kind, message, tag, and props.
+pub struct StatusEntry {
+    status: StatusCode,
+    str_specs: Vec<StringSpec<'static>>,
+    expose_payload: bool,
+    expose_src: bool,
+}
+
+impl StatusEntry {
+    /// Entry with status code `status` that exposes no [`StringSpec`]s, payload, or source.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            str_specs: Vec::new(),
+            expose_payload: false,
+            expose_src: false,
+        }
+    }
+
+    /// Returns `self` exposing the given [`StringSpec`]s in the response body's `other` field.
+    pub fn with_str_specs(
+        mut self,
+        str_specs: impl IntoIterator<Item = StringSpec<'static>>,
+    ) -> Self {
+        self.str_specs = str_specs.into_iter().collect();
+        self
+    }
+
+    /// Returns `self` exposing the error's payload, if it is of a type registered with the mapper
+    /// (see [`StatusMapper::with_payload_type`]).
+    pub fn with_payload(mut self) -> Self {
+        self.expose_payload = true;
+        self
+    }
+
+    /// Returns `self` exposing the error's source, as its recursive message.
+    pub fn with_src(mut self) -> Self {
+        self.expose_src = true;
+        self
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+}
+
+// endregion:   --- StatusEntry
+
+//===========================
+// region:      --- StatusMapper
+
+type PayloadToJson = fn(&Error) -> Option<Value>;
+
+fn payload_to_json<T: Payload + Serialize>(err: &Error) -> Option<Value> {
+    let payload = err.downcast_payload_ref::<T>()?;
+    serde_json::to_value(payload).ok()
+}
+
+/// Configurable mapper of [`Error`]s to a status code and a serializable error, as an alternative to
+/// [`super::default_mapper`]. The [`StatusEntry`] for an error is the one for its kind id, if any, otherwise
+/// the one for its tag or the closest of the tag's ancestors (see [`Tag::is_a`]), if any, otherwise the
+/// default entry.
+///
+/// [`crate::error::MULTI_ERROR`]s get the most severe (highest) status code of their children and, if
+/// their entry exposes the payload, the children as a nested array payload.
+///
+/// Only server errors (5xx status codes) are logged.
+///
+/// ```
+/// use foa::{error::{StringSpec, VALIDATION_TAG}, web::{StatusEntry, StatusMapper}};
+/// use http::StatusCode;
+///
+/// let mapper = StatusMapper::new()
+///     .with_tag(&VALIDATION_TAG, StatusEntry::new(StatusCode::UNPROCESSABLE_ENTITY).with_payload())
+///     .with_default(
+///         StatusEntry::new(StatusCode::INTERNAL_SERVER_ERROR)
+///             .with_str_specs([StringSpec::Recursive]),
+///     )
+///     .into_mapper();
+/// # let _ = mapper;
+/// ```
+pub struct StatusMapper {
+    by_kind: HashMap<&'static str, StatusEntry>,
+    by_tag: HashMap<&'static str, StatusEntry>,
+    default: StatusEntry,
+    payload_types: Vec<PayloadToJson>,
+}
+
+impl StatusMapper {
+    /// Mapper with entries for the standard tags that expose nothing else:
+    /// - [`CLIENT_TAG`] to status code 400;
+    /// - [`VALIDATION_TAG`] to status code 400, exposing the payload;
+    /// - [`NOT_FOUND_TAG`] to 404, [`CONFLICT_TAG`] to 409, [`AUTH_TAG`] to 401, [`FORBIDDEN_TAG`] to 403,
+    ///   and [`RATE_LIMITED_TAG`] to 429;
+    /// - [`TRANSIENT_TAG`] to 503;
+    ///
+    /// and a default entry with status code 500. [`ValidationError`] is registered as a payload type.
+    pub fn new() -> Self {
+        Self {
+            by_kind: HashMap::new(),
+            by_tag: HashMap::new(),
+            default: StatusEntry::new(StatusCode::INTERNAL_SERVER_ERROR),
+            payload_types: vec![payload_to_json::<ValidationError>],
+        }
+        .with_tag(&CLIENT_TAG, StatusEntry::new(StatusCode::BAD_REQUEST))
+        .with_tag(
+            &VALIDATION_TAG,
+            StatusEntry::new(StatusCode::BAD_REQUEST).with_payload(),
+        )
+        .with_tag(&NOT_FOUND_TAG, StatusEntry::new(StatusCode::NOT_FOUND))
+        .with_tag(&CONFLICT_TAG, StatusEntry::new(StatusCode::CONFLICT))
+        .with_tag(&AUTH_TAG, StatusEntry::new(StatusCode::UNAUTHORIZED))
+        .with_tag(&FORBIDDEN_TAG, StatusEntry::new(StatusCode::FORBIDDEN))
+        .with_tag(
+            &RATE_LIMITED_TAG,
+            StatusEntry::new(StatusCode::TOO_MANY_REQUESTS),
+        )
+        .with_tag(
+            &TRANSIENT_TAG,
+            StatusEntry::new(StatusCode::SERVICE_UNAVAILABLE),
+        )
+    }
+
+    /// Returns `self` with `entry` for errors of kind `kind_id`.
+    pub fn with_kind(mut self, kind_id: &'static KindId, entry: StatusEntry) -> Self {
+        self.by_kind.insert(kind_id.0, entry);
+        self
+    }
+
+    /// Returns `self` with `entry` for errors with tag `tag`.
+    pub fn with_tag(mut self, tag: &'static Tag, entry: StatusEntry) -> Self {
+        self.by_tag.insert(tag.name(), entry);
+        self
+    }
+
+    /// Returns `self` with `entry` as the default entry.
+    pub fn with_default(mut self, entry: StatusEntry) -> Self {
+        self.default = entry;
+        self
+    }
+
+    /// Returns `self` with `T` registered as a payload type that can be exposed (see [`StatusEntry::with_payload`]).
+    pub fn with_payload_type<T: Payload + Serialize>(mut self) -> Self {
+        self.payload_types.push(payload_to_json::<T>);
+        self
+    }
+
+    /// Returns the entry that applies to `err`.
+    pub fn entry(&self, err: &Error) -> &StatusEntry {
+        self.by_kind
+            .get(err.kind_id().0)
+            .or_else(|| {
+                err.tag()
+                    .ancestors()
+                    .find_map(|tag| self.by_tag.get(tag.name()))
+            })
+            .unwrap_or(&self.default)
+    }
+
+    /// Returns the status code for `err`.
+    pub fn status_code(&self, err: &Error) -> StatusCode {
+        match err.downcast_payload_ref::<MultiError>() {
+            Some(multi) => multi
+                .children()
+                .iter()
+                .map(|child| self.status_code(&child.error))
+                .max()
+                .unwrap_or(self.entry(err).status),
+            None => self.entry(err).status,
+        }
+    }
+
+    /// Maps `err` to its status code and serializable error.
+    pub fn map(&self, err: Error) -> (StatusCode, JserBoxError) {
+        self.localized_map::<NullCtx>(err)
+    }
+
+    /// Same as [`Self::map`] but with messages localized for `CTX`. `CTX`'s locale must be available where
+    /// the mapper runs.
+    pub fn localized_map<CTX: ErrCtx>(&self, err: Error) -> (StatusCode, JserBoxError) {
+        let status_code = self.status_code(&err);
+        if status_code.is_server_error() {
+            log!(Level::Error, "{}", error_string_for_error_level(&err));
+        }
+
+        let entry = self.entry(&err);
+        let payload = if !entry.expose_payload {
+            None
+        } else if let Some(multi) = err.downcast_payload_ref::<MultiError>() {
+            let children = multi
+                .to_ser_children()
+                .into_iter()
+                .map(|child| child.localized::<CTX>())
+                .collect::<Vec<_>>();
+            serde_json::to_value(children).ok()
+        } else {
+            self.payload_types.iter().find_map(|to_json| to_json(&err))
+        };
+        let ser_err = err
+            .into_sererror_speced(&entry.str_specs, payload, entry.expose_src)
+            .localized::<CTX>();
+        (status_code, ser_err.into())
+    }
+
+    /// Returns a mapper function, e.g., for
+    /// [`HandlerAsyncFn2rsWithErrorMapper`](super::axum::HandlerAsyncFn2rsWithErrorMapper).
+    pub fn into_mapper(self) -> impl Fn(Error) -> (StatusCode, JserBoxError) + Clone + Send + Sync {
+        let mapper = Arc::new(self);
+        move |err| mapper.map(err)
+    }
+
+    /// Same as [`Self::into_mapper`] but with messages localized for `CTX` (see [`Self::localized_map`]).
+    pub fn into_localized_mapper<CTX: ErrCtx>(
+        self,
+    ) -> impl Fn(Error) -> (StatusCode, JserBoxError) + Clone + Send + Sync {
+        let mapper = Arc::new(self);
+        move |err| mapper.localized_map::<CTX>(err)
+    }
+}
+
+impl Default for StatusMapper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// endregion:   --- StatusMapper
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::{BasicKind, PayloadKind, StdBoxError, TrivialError};
+
+    static DUP_TAG: Tag = Tag::new("MAPPER_DUP").with_parent(&CONFLICT_TAG);
+
+    static DUP_ERROR: BasicKind<StdBoxError> = BasicKind::new("MAPPER_DUP_ERROR", None, &DUP_TAG);
+
+    static GONE_ERROR: BasicKind = BasicKind::new("MAPPER_GONE_ERROR", None, &CONFLICT_TAG);
+
+    #[derive(Debug, Serialize)]
+    struct Limit {
+        retry_after: u32,
+    }
+
+    static RATE_ERROR: PayloadKind<Limit> =
+        PayloadKind::new_with_payload("MAPPER_RATE_ERROR", None, &CONFLICT_TAG);
+
+    static MISSING_ERROR: BasicKind = BasicKind::new("MAPPER_MISSING_ERROR", None, &NOT_FOUND_TAG);
+
+    static DENIED_ERROR: BasicKind = BasicKind::new("MAPPER_DENIED_ERROR", None, &FORBIDDEN_TAG);
+
+    static THROTTLED_ERROR: BasicKind =
+        BasicKind::new("MAPPER_THROTTLED_ERROR", None, &RATE_LIMITED_TAG);
+
+    static FOO_TAG: Tag = Tag::new("FOO");
+
+    static FOO_ERROR: BasicKind = BasicKind::new("MAPPER_FOO_ERROR", None, &FOO_TAG);
+
+    #[test]
+    fn test_status_mapper() {
+        let mapper = StatusMapper::new()
+            .with_payload_type::<Limit>()
+            .with_tag(
+                &CONFLICT_TAG,
+                StatusEntry::new(StatusCode::CONFLICT)
+                    .with_src()
+                    .with_str_specs([StringSpec::Recursive]),
+            )
+            .with_kind(
+                GONE_ERROR.kind_id(),
+                StatusEntry::new(StatusCode::NOT_FOUND),
+            )
+            .with_kind(
+                RATE_ERROR.kind_id(),
+                StatusEntry::new(StatusCode::TOO_MANY_REQUESTS).with_payload(),
+            )
+            .into_mapper();
+
+        let (status, body) =
+            mapper(DUP_ERROR.error_with_src(StdBoxError::new(TrivialError("dup"))));
+        assert_eq!(status, StatusCode::CONFLICT);
+        let json = serde_json::to_value(&body).unwrap();
+        assert_eq!(json["src"], "recursive_msg(dup)");
+        assert_eq!(
+            json["other"]["recursive_msg"],
+            "MAPPER_DUP_ERROR, source_msg=[dup]"
+        );
+
+        let (status, body) = mapper(GONE_ERROR.error());
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let json = serde_json::to_value(&body).unwrap();
+        assert!(json["other"].as_object().unwrap().is_empty());
+        assert!(json.get("location").is_none());
+        assert!(json.get("created_at").is_none());
+
+        let (status, body) = mapper(RATE_ERROR.error_with_payload(Limit { retry_after: 3 }));
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            serde_json::to_value(&body).unwrap()["payload"]["retry_after"],
+            3
+        );
+
+        let (status, _) = mapper(MISSING_ERROR.error());
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = mapper(DENIED_ERROR.error());
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = mapper(THROTTLED_ERROR.error());
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+        let (status, body) = mapper(FOO_ERROR.error());
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(serde_json::to_value(&body).unwrap()["src"].is_null());
+
+        let multi = [
+            GONE_ERROR.error(),
+            DUP_ERROR.error_with_src(StdBoxError::new(TrivialError("x"))),
+        ]
+        .into_iter()
+        .collect::<MultiError>();
+        assert_eq!(mapper(multi.into_error()).0, StatusCode::CONFLICT);
+    }
+}